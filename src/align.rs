@@ -0,0 +1,87 @@
+/// One of the nine standard anchors for placing a rectangle inside another.
+///
+/// See [`Rect::aligned_in`](crate::Rect::aligned_in).
+///
+/// ## Examples
+///
+/// ```rust
+/// use ixy::{Align2, Rect};
+///
+/// let screen = Rect::from_ltwh(0, 0, 80, 24);
+/// let dialog = Rect::from_ltwh(0, 0, 20, 6);
+/// assert_eq!(dialog.aligned_in(screen, Align2::Center), Rect::from_ltwh(30, 9, 20, 6));
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum Align2 {
+    /// Aligned to the top-left corner.
+    #[default]
+    TopLeft,
+
+    /// Centered horizontally, aligned to the top edge.
+    Top,
+
+    /// Aligned to the top-right corner.
+    TopRight,
+
+    /// Aligned to the left edge, centered vertically.
+    Left,
+
+    /// Centered horizontally and vertically.
+    Center,
+
+    /// Aligned to the right edge, centered vertically.
+    Right,
+
+    /// Aligned to the bottom-left corner.
+    BottomLeft,
+
+    /// Centered horizontally, aligned to the bottom edge.
+    Bottom,
+
+    /// Aligned to the bottom-right corner.
+    BottomRight,
+}
+
+impl Align2 {
+    /// All anchors, in row-major order starting at [`Align2::TopLeft`].
+    pub const ALL: [Self; 9] = [
+        Self::TopLeft,
+        Self::Top,
+        Self::TopRight,
+        Self::Left,
+        Self::Center,
+        Self::Right,
+        Self::BottomLeft,
+        Self::Bottom,
+        Self::BottomRight,
+    ];
+
+    /// Returns how far along each axis the anchor is, in halves (`0`, `1`, or `2`).
+    pub(crate) const fn halves(self) -> (usize, usize) {
+        match self {
+            Self::TopLeft => (0, 0),
+            Self::Top => (1, 0),
+            Self::TopRight => (2, 0),
+            Self::Left => (0, 1),
+            Self::Center => (1, 1),
+            Self::Right => (2, 1),
+            Self::BottomLeft => (0, 2),
+            Self::Bottom => (1, 2),
+            Self::BottomRight => (2, 2),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn all_is_row_major() {
+        for (i, align) in Align2::ALL.into_iter().enumerate() {
+            assert_eq!(align.halves(), (i % 3, i / 3));
+        }
+    }
+}
@@ -0,0 +1,93 @@
+use crate::{Pos, int::SignedInt};
+
+/// One of the four cardinal directions, in screen coordinates (where `y` increases downwards).
+///
+/// ## Examples
+///
+/// ```rust
+/// use ixy::{Direction, Pos};
+///
+/// assert_eq!(Direction::Up.opposite(), Direction::Down);
+/// assert_eq!(Direction::Up.to_pos::<i32>(), Pos::new(0, -1));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum Direction {
+    /// Towards negative `y`.
+    Up,
+
+    /// Towards positive `x`.
+    Right,
+
+    /// Towards positive `y`.
+    Down,
+
+    /// Towards negative `x`.
+    Left,
+}
+
+impl Direction {
+    /// All directions, in clockwise order starting at [`Direction::Up`].
+    pub const ALL: [Self; 4] = [Self::Up, Self::Right, Self::Down, Self::Left];
+
+    /// Returns the direction pointing the opposite way.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use ixy::Direction;
+    ///
+    /// assert_eq!(Direction::Left.opposite(), Direction::Right);
+    /// ```
+    #[must_use]
+    pub const fn opposite(self) -> Self {
+        match self {
+            Self::Up => Self::Down,
+            Self::Right => Self::Left,
+            Self::Down => Self::Up,
+            Self::Left => Self::Right,
+        }
+    }
+
+    /// Returns the unit offset of one step in this direction.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use ixy::{Direction, Pos};
+    ///
+    /// assert_eq!(Direction::Right.to_pos::<i8>(), Pos::X);
+    /// assert_eq!(Direction::Up.to_pos::<i8>(), Pos::NEG_Y);
+    /// ```
+    #[must_use]
+    pub const fn to_pos<T: SignedInt>(self) -> Pos<T> {
+        match self {
+            Self::Up => Pos::NEG_Y,
+            Self::Right => Pos::X,
+            Self::Down => Pos::Y,
+            Self::Left => Pos::NEG_X,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn opposite_is_involution() {
+        for direction in Direction::ALL {
+            assert_eq!(direction.opposite().opposite(), direction);
+            assert_ne!(direction.opposite(), direction);
+        }
+    }
+
+    #[test]
+    fn opposite_offsets_cancel() {
+        for direction in Direction::ALL {
+            let sum = direction.to_pos::<i32>() + direction.opposite().to_pos();
+            assert_eq!(sum, Pos::ORIGIN);
+        }
+    }
+}
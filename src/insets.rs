@@ -0,0 +1,118 @@
+/// Distances from each side of a rectangle, such as padding, borders, or margins.
+///
+/// See [`Rect::inflate_by`](crate::Rect::inflate_by) and
+/// [`Rect::deflate_by`](crate::Rect::deflate_by).
+///
+/// ## Examples
+///
+/// ```rust
+/// use ixy::{Insets, Rect};
+///
+/// let rect = Rect::from_ltwh(0, 0, 10, 10);
+/// let padding = Insets::symmetric(2, 1);
+/// assert_eq!(rect.deflate_by(padding), Rect::from_ltwh(2, 1, 6, 8));
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct Insets {
+    /// Distance from the left side.
+    pub left: usize,
+
+    /// Distance from the top side.
+    pub top: usize,
+
+    /// Distance from the right side.
+    pub right: usize,
+
+    /// Distance from the bottom side.
+    pub bottom: usize,
+}
+
+impl Insets {
+    /// No distance on any side.
+    pub const ZERO: Self = Self::uniform(0);
+
+    /// Creates new insets, in the same order as [`Rect::from_ltrb`](crate::Rect::from_ltrb).
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use ixy::Insets;
+    ///
+    /// let insets = Insets::new(1, 2, 3, 4);
+    /// assert_eq!((insets.left, insets.top), (1, 2));
+    /// assert_eq!((insets.right, insets.bottom), (3, 4));
+    /// ```
+    #[must_use]
+    pub const fn new(left: usize, top: usize, right: usize, bottom: usize) -> Self {
+        Self {
+            left,
+            top,
+            right,
+            bottom,
+        }
+    }
+
+    /// Creates insets with the same distance on every side.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use ixy::Insets;
+    ///
+    /// assert_eq!(Insets::uniform(3), Insets::new(3, 3, 3, 3));
+    /// ```
+    #[must_use]
+    pub const fn uniform(n: usize) -> Self {
+        Self::new(n, n, n, n)
+    }
+
+    /// Creates insets with `horizontal` on the left and right, and `vertical` on the top and bottom.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use ixy::Insets;
+    ///
+    /// assert_eq!(Insets::symmetric(2, 1), Insets::new(2, 1, 2, 1));
+    /// ```
+    #[must_use]
+    pub const fn symmetric(horizontal: usize, vertical: usize) -> Self {
+        Self::new(horizontal, vertical, horizontal, vertical)
+    }
+
+    /// Returns the total horizontal distance, `left + right`.
+    ///
+    /// Saturates at [`usize::MAX`] instead of overflowing.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use ixy::Insets;
+    ///
+    /// assert_eq!(Insets::new(1, 2, 3, 4).horizontal(), 4);
+    /// assert_eq!(Insets::uniform(usize::MAX).horizontal(), usize::MAX);
+    /// ```
+    #[must_use]
+    pub const fn horizontal(&self) -> usize {
+        self.left.saturating_add(self.right)
+    }
+
+    /// Returns the total vertical distance, `top + bottom`.
+    ///
+    /// Saturates at [`usize::MAX`] instead of overflowing.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use ixy::Insets;
+    ///
+    /// assert_eq!(Insets::new(1, 2, 3, 4).vertical(), 6);
+    /// assert_eq!(Insets::uniform(usize::MAX).vertical(), usize::MAX);
+    /// ```
+    #[must_use]
+    pub const fn vertical(&self) -> usize {
+        self.top.saturating_add(self.bottom)
+    }
+}
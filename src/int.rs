@@ -139,6 +139,16 @@ pub trait Int:
     /// If the value cannot be represented by `Self`, then [`None`] is returned.
     fn checked_from_usize(value: usize) -> Option<Self>;
 
+    /// Converts the value of `self` to a [`u64`].
+    ///
+    /// If the value cannot be represented by a [`u64`], then [`None`] is returned.
+    fn checked_to_u64(self) -> Option<u64>;
+
+    /// Checked integer addition.
+    ///
+    /// If the result overflows, then [`None`] is returned.
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+
     /// Returns the absolute value of `self`.
     #[must_use]
     fn abs(self) -> Self;
@@ -146,6 +156,25 @@ pub trait Int:
     /// Returns the number of trailing zeros in the binary representation of `self`.
     #[must_use]
     fn trailing_zeros(self) -> u32;
+
+    /// Calculates the quotient of Euclidean division of `self` by `rhs`.
+    ///
+    /// Unlike [`Div`], the result is rounded towards negative infinity for positive `rhs`, which
+    /// is usually what is wanted when mapping coordinates to cells.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `rhs` is `0`.
+    #[must_use]
+    fn div_euclid(self, rhs: Self) -> Self;
+
+    /// Calculates the least non-negative remainder of `self (mod rhs)`.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `rhs` is `0`.
+    #[must_use]
+    fn rem_euclid(self, rhs: Self) -> Self;
 }
 
 /// Generic trait for the built-in Rust signed integer types.
@@ -161,7 +190,20 @@ pub trait SignedInt: Int + Neg<Output = Self> {
 macro_rules! impl_unsigned_int {
   ($($t:ty),*) => {
     $(
-      impl Sealed for $t {}
+      impl Sealed for $t {
+        #[allow(clippy::cast_lossless)]
+        fn abs_diff_u128(self, other: Self) -> u128 {
+          self.abs_diff(other) as u128
+        }
+
+        fn checked_add_u128(self, rhs: u128) -> Option<Self> {
+          self.checked_add(Self::try_from(rhs).ok()?)
+        }
+
+        fn checked_sub_u128(self, rhs: u128) -> Option<Self> {
+          self.checked_sub(Self::try_from(rhs).ok()?)
+        }
+      }
 
       impl Int for $t {
         const ZERO: Self = 0;
@@ -177,6 +219,14 @@ macro_rules! impl_unsigned_int {
           Self::try_from(value).ok()
         }
 
+        fn checked_to_u64(self) -> Option<u64> {
+          u64::try_from(self).ok()
+        }
+
+        fn checked_add(self, rhs: Self) -> Option<Self> {
+          self.checked_add(rhs)
+        }
+
         fn abs(self) -> Self {
           self
         }
@@ -184,15 +234,36 @@ macro_rules! impl_unsigned_int {
         fn trailing_zeros(self) -> u32 {
           self.trailing_zeros()
         }
+
+        fn div_euclid(self, rhs: Self) -> Self {
+          self.div_euclid(rhs)
+        }
+
+        fn rem_euclid(self, rhs: Self) -> Self {
+          self.rem_euclid(rhs)
+        }
       }
     )*
   };
 }
 
 macro_rules! impl_signed_int {
-  ($($t:ty),*) => {
+  ($($t:ty => $u:ty),*) => {
     $(
-      impl Sealed for $t {}
+      impl Sealed for $t {
+        #[allow(clippy::cast_lossless)]
+        fn abs_diff_u128(self, other: Self) -> u128 {
+          self.abs_diff(other) as u128
+        }
+
+        fn checked_add_u128(self, rhs: u128) -> Option<Self> {
+          self.checked_add_unsigned(<$u>::try_from(rhs).ok()?)
+        }
+
+        fn checked_sub_u128(self, rhs: u128) -> Option<Self> {
+          self.checked_sub_unsigned(<$u>::try_from(rhs).ok()?)
+        }
+      }
 
       impl Int for $t {
         const ZERO: Self = 0;
@@ -208,6 +279,14 @@ macro_rules! impl_signed_int {
           Self::try_from(value).ok()
         }
 
+        fn checked_to_u64(self) -> Option<u64> {
+          u64::try_from(self).ok()
+        }
+
+        fn checked_add(self, rhs: Self) -> Option<Self> {
+          self.checked_add(rhs)
+        }
+
         fn abs(self) -> Self {
           if self < Self::ZERO {
             -self
@@ -219,6 +298,14 @@ macro_rules! impl_signed_int {
         fn trailing_zeros(self) -> u32 {
           self.trailing_zeros()
         }
+
+        fn div_euclid(self, rhs: Self) -> Self {
+          self.div_euclid(rhs)
+        }
+
+        fn rem_euclid(self, rhs: Self) -> Self {
+          self.rem_euclid(rhs)
+        }
       }
 
       impl SignedInt for $t {
@@ -240,12 +327,12 @@ impl_unsigned_int!(
 
 #[rustfmt::skip]
 impl_signed_int!(
-    i8,
-    i16,
-    i32,
-    i64,
-    i128,
-    isize
+    i8 => u8,
+    i16 => u16,
+    i32 => u32,
+    i64 => u64,
+    i128 => u128,
+    isize => usize
 );
 
 #[cfg(test)]
@@ -349,4 +436,26 @@ mod tests {
         assert_eq!(Int::trailing_zeros(4u128), 2);
         assert_eq!(Int::trailing_zeros(4usize), 2);
     }
+
+    #[test]
+    fn checked_to_u64() {
+        assert_eq!(255u8.checked_to_u64(), Some(255));
+        assert_eq!((-1i8).checked_to_u64(), None);
+        assert_eq!(i64::MAX.checked_to_u64(), Some(i64::MAX as u64));
+        assert_eq!(u128::MAX.checked_to_u64(), None);
+    }
+
+    #[test]
+    fn signed_int_div_rem_euclid() {
+        assert_eq!(Int::div_euclid(-7i32, 4), -2);
+        assert_eq!(Int::rem_euclid(-7i32, 4), 1);
+        assert_eq!(Int::div_euclid(7i32, 4), 1);
+        assert_eq!(Int::rem_euclid(7i32, 4), 3);
+    }
+
+    #[test]
+    fn unsigned_int_div_rem_euclid() {
+        assert_eq!(Int::div_euclid(7u8, 4), 1);
+        assert_eq!(Int::rem_euclid(7u8, 4), 3);
+    }
 }
@@ -1,10 +1,29 @@
 #![allow(unreachable_pub)]
 
+use core::str::FromStr;
+
 use crate::int::Int;
 
 /// Used to seal traits for the crate.
+///
+/// Also provides overflow-free arithmetic for the crate's own generic integer math.
 #[doc(hidden)]
-pub trait Sealed {}
+pub trait Sealed: Sized {
+    /// Returns the absolute difference between `self` and `other`, which never overflows.
+    fn abs_diff_u128(self, other: Self) -> u128;
+
+    /// Adds `rhs` to `self`, returning [`None`] if the result cannot be represented by `Self`.
+    fn checked_add_u128(self, rhs: u128) -> Option<Self>;
+
+    /// Subtracts `rhs` from `self`, returning [`None`] if the result cannot be represented by `Self`.
+    fn checked_sub_u128(self, rhs: u128) -> Option<Self>;
+}
+
+/// Parses a pair of comma-separated values, ignoring whitespace around each value.
+pub fn parse_pair<T: FromStr>(s: &str) -> Option<(T, T)> {
+    let (a, b) = s.split_once(',')?;
+    Some((a.trim().parse().ok()?, b.trim().parse().ok()?))
+}
 
 /// Calculates the greatest common divisor (GCD) of two integers.
 ///
@@ -59,10 +78,125 @@ pub fn isqrt<T: Int>(n: T) -> T {
     x
 }
 
+/// Divides `value` by a positive `rhs`, rounding towards positive infinity.
+pub fn div_ceil_euclid<T: Int>(value: T, rhs: T) -> T {
+    let quotient = value.div_euclid(rhs);
+    if value.rem_euclid(rhs) == T::ZERO {
+        quotient
+    } else {
+        quotient + T::ONE
+    }
+}
+
+/// Rounds `value` up (towards positive infinity) to a multiple of a positive `step`.
+pub fn snap_up<T: Int>(value: T, step: T) -> T {
+    div_ceil_euclid(value, step) * step
+}
+
+/// Interpolates from `a` (at `num = 0`) to `b` (at `num = den`), rounding to the nearest integer.
+///
+/// Ties are rounded away from `a`, and `num == den` always returns exactly `b`. The difference
+/// between `a` and `b` is computed in a wider type, so endpoints far apart never overflow.
+///
+/// ## Panics
+///
+/// Panics if `den` is `0`, or if the result (when `num > den`) cannot be represented by `T`.
+pub fn lerp<T: Int>(a: T, b: T, num: usize, den: usize) -> T {
+    interpolate(a, b, num, den, true)
+}
+
+/// Interpolates like [`lerp`], but rounding towards `a`.
+///
+/// ## Panics
+///
+/// Panics if `den` is `0`, or if the result (when `num > den`) cannot be represented by `T`.
+pub fn lerp_towards_a<T: Int>(a: T, b: T, num: usize, den: usize) -> T {
+    interpolate(a, b, num, den, false)
+}
+
+/// Interpolates from `a` to `b`, rounding to the nearest integer if `nearest`, or towards `a`.
+fn interpolate<T: Int>(a: T, b: T, num: usize, den: usize, nearest: bool) -> T {
+    assert!(den > 0, "denominator must be greater than 0");
+    let (num, den) = (num as u128, den as u128);
+    let diff = a.abs_diff_u128(b);
+    // Split `diff * num / den` so that no intermediate value overflows when `num <= den`.
+    let partial = (diff % den) * num;
+    let round_up = nearest && partial % den >= den - partial % den;
+    (diff / den)
+        .checked_mul(num)
+        .and_then(|whole| whole.checked_add(partial / den + u128::from(round_up)))
+        .and_then(|delta| {
+            if b >= a {
+                a.checked_add_u128(delta)
+            } else {
+                a.checked_sub_u128(delta)
+            }
+        })
+        .expect("interpolated value out of range")
+}
+
+/// A small, deterministic random number generator for tests (xorshift64).
+#[cfg(all(test, feature = "rand"))]
+pub struct TestRng(pub u64);
+
+#[cfg(all(test, feature = "rand"))]
+impl rand::RngCore for TestRng {
+    fn next_u32(&mut self) -> u32 {
+        u32::try_from(self.next_u64() >> 32).unwrap()
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn fill_bytes(&mut self, dst: &mut [u8]) {
+        for chunk in dst.chunks_mut(8) {
+            let bytes = self.next_u64().to_le_bytes();
+            chunk.copy_from_slice(&bytes[..chunk.len()]);
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn lerp_endpoints_and_rounding() {
+        assert_eq!(lerp(3, 9, 0, 4), 3);
+        assert_eq!(lerp(3, 9, 4, 4), 9);
+        assert_eq!(lerp(3, 9, 1, 4), 5);
+        assert_eq!(lerp(9, 3, 1, 4), 7);
+        assert_eq!(lerp(0u8, 255, 1, 2), 128);
+        assert_eq!(lerp(-10, 10, 1, 3), -3);
+    }
+
+    #[test]
+    fn lerp_straddling_zero() {
+        assert_eq!(lerp(-100i8, 100, 1, 2), 0);
+        assert_eq!(lerp(100i8, -100, 1, 2), 0);
+        assert_eq!(lerp(i8::MIN, i8::MAX, 1, 1), i8::MAX);
+        assert_eq!(lerp(i8::MAX, i8::MIN, 3, 4), -64);
+        assert_eq!(lerp(i128::MIN, i128::MAX, 1, 2), 0);
+        assert_eq!(lerp(0u128, u128::MAX, 1, 1), u128::MAX);
+    }
+
+    #[test]
+    fn lerp_towards_a_truncates() {
+        assert_eq!(lerp_towards_a(0, 7, 1, 2), 3);
+        assert_eq!(lerp_towards_a(0, -7, 1, 2), -3);
+        assert_eq!(lerp_towards_a(0i16, 200, 199, 200), 199);
+    }
+
+    #[test]
+    #[should_panic(expected = "interpolated value out of range")]
+    fn lerp_extrapolation_overflow() {
+        let _ = lerp(0i8, 100, 2, 1);
+    }
+
     #[test]
     fn test_gcd_basic_cases() {
         assert_eq!(gcd(10, 2), 2);
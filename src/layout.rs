@@ -7,7 +7,11 @@
 //! - [`ColumnMajor`] for column-major order
 //! - [`Block`] for block-based traversal (where the inner blocks can themselves have a layout)
 //!
-//! In addition, the [`Linear`] trait provides mapping and iterating methods for linear data.
+//! [`Shuffled`] additionally visits positions in a pseudo-random, but reproducible, order.
+//!
+//! In addition, the [`Linear`] trait provides mapping and iterating methods for linear data, and
+//! the [`constraints`] module splits a rectangle into children along one axis, and the [`tiles`]
+//! module traverses large rectangles in cache-sized tiles.
 
 use core::ops::Range;
 
@@ -16,12 +20,19 @@ use crate::{Pos, Rect, Size, int::Int};
 mod block;
 pub use block::Block;
 
+pub mod constraints;
+
 mod col_major;
 pub use col_major::ColumnMajor;
 
 mod row_major;
 pub use row_major::RowMajor;
 
+mod shuffled;
+pub use shuffled::Shuffled;
+
+pub mod tiles;
+
 /// Defines iterating orders for traversing a 2D layout.
 pub trait Traversal {
     /// Returns an iterator over the positions.
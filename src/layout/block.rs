@@ -3,6 +3,7 @@ use core::{marker::PhantomData, ops::Range};
 use crate::{
     Pos, Rect, Size,
     int::Int,
+    internal,
     layout::{Linear, RowMajor, Traversal},
 };
 
@@ -69,6 +70,80 @@ pub struct Block<const W: usize, const H: usize, G = RowMajor, C = G> {
     cell: PhantomData<C>,
 }
 
+impl<const W: usize, const H: usize, G, C> Block<W, H, G, C> {
+    /// Returns the smallest block-aligned rectangle that covers `rect`.
+    ///
+    /// The edges are expanded outwards to the nearest multiple of `W` (horizontally) and `H`
+    /// (vertically), so the result can use the aligned fast paths of [`Linear`].
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use ixy::{Rect, layout::Block};
+    ///
+    /// let rect = Rect::from_ltrb(1, 1, 5, 3).unwrap();
+    /// assert_eq!(Block::<4, 2>::align_rect(rect), Rect::from_ltrb(0, 0, 8, 4).unwrap());
+    /// ```
+    #[must_use]
+    pub fn align_rect<T: Int>(rect: Rect<T>) -> Rect<T> {
+        rect.snap_to_grid(Size::new(W, H))
+    }
+
+    /// Splits `rect` into a block-aligned core, and the remainders around it.
+    ///
+    /// Returns `(core, [top, bottom, left, right])`, where:
+    ///
+    /// - `core` is the largest block-aligned rectangle within `rect`;
+    /// - `top` and `bottom` span the full width of `rect`, above and below `core`;
+    /// - `left` and `right` span the height of `core`, to its left and right.
+    ///
+    /// Any of the rectangles may be empty. If `rect` does not contain a full block, `core` is
+    /// empty and `top` is `rect` itself.
+    ///
+    /// ```txt
+    /// +--------------+
+    /// |     top      |
+    /// +--+--------+--+
+    /// |l |  core  | r|
+    /// +--+--------+--+
+    /// |    bottom    |
+    /// +--------------+
+    /// ```
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use ixy::{Rect, layout::Block};
+    ///
+    /// let rect = Rect::from_ltrb(1, 1, 7, 5).unwrap();
+    /// let (core, [top, bottom, left, right]) = Block::<2, 2>::split_aligned(rect);
+    /// assert_eq!(core, Rect::from_ltrb(2, 2, 6, 4).unwrap());
+    /// assert_eq!(top, Rect::from_ltrb(1, 1, 7, 2).unwrap());
+    /// assert_eq!(bottom, Rect::from_ltrb(1, 4, 7, 5).unwrap());
+    /// assert_eq!(left, Rect::from_ltrb(1, 2, 2, 4).unwrap());
+    /// assert_eq!(right, Rect::from_ltrb(6, 2, 7, 4).unwrap());
+    /// ```
+    #[must_use]
+    pub fn split_aligned<T: Int>(rect: Rect<T>) -> (Rect<T>, [Rect<T>; 4]) {
+        let (block_w, block_h) = (T::from_usize(W), T::from_usize(H));
+        let (l, t, r, b) = (rect.left(), rect.top(), rect.right(), rect.bottom());
+
+        let (mut inner_l, mut inner_r) = (internal::snap_up(l, block_w), r - r.rem_euclid(block_w));
+        let (mut inner_t, mut inner_b) = (internal::snap_up(t, block_h), b - b.rem_euclid(block_h));
+        if inner_l >= inner_r || inner_t >= inner_b {
+            // No full block fits; everything is in the top remainder.
+            (inner_l, inner_r, inner_t, inner_b) = (r, r, b, b);
+        }
+
+        let core = Rect::from_ltrb_unchecked(inner_l, inner_t, inner_r, inner_b);
+        let top = Rect::from_ltrb_unchecked(l, t, r, inner_t);
+        let bottom = Rect::from_ltrb_unchecked(l, inner_b, r, b);
+        let left = Rect::from_ltrb_unchecked(l, inner_t, inner_l, inner_b);
+        let right = Rect::from_ltrb_unchecked(inner_r, inner_t, r, inner_b);
+        (core, [top, bottom, left, right])
+    }
+}
+
 impl<const W: usize, const H: usize, G: Traversal, C: Traversal> Traversal for Block<W, H, G, C> {
     /// Returns an iterator over the positions in the specified rectangle.
     ///
@@ -651,7 +726,7 @@ mod tests {
             4, 5, 6, 7,
         ];
         let size = Size::new(4, 2);
-        assert_eq!(Block::<2, 2>::slice_aligned(slice, size, 2), &[]);
+        assert_eq!(Block::<2, 2>::slice_aligned(slice, size, 2), &[] as &[i32]);
     }
 
     #[test]
@@ -728,4 +803,64 @@ mod tests {
             Some(&mut [0, 1, 2, 3, 4, 5, 6, 7][..])
         );
     }
+
+    #[test]
+    fn align_rect_expands() {
+        let rect = Rect::from_ltrb(-1, 3, 2, 5).unwrap();
+        assert_eq!(
+            Block::<2, 4>::align_rect(rect),
+            Rect::from_ltrb(-2, 0, 2, 8).unwrap()
+        );
+    }
+
+    #[test]
+    fn align_rect_already_aligned() {
+        let rect = Rect::from_ltwh(2, 2, 4, 2);
+        assert_eq!(Block::<2, 2>::align_rect(rect), rect);
+    }
+
+    #[test]
+    fn split_aligned_already_aligned() {
+        let rect = Rect::from_ltwh(0, 0, 4, 4);
+        let (core, edges) = Block::<2, 2>::split_aligned(rect);
+        assert_eq!(core, rect);
+        assert!(edges.iter().all(Rect::is_empty));
+    }
+
+    #[test]
+    fn split_aligned_covers_rect_exactly() {
+        let rect = Rect::from_ltrb(-3, 1, 9, 10).unwrap();
+        let (core, edges) = Block::<4, 4>::split_aligned(rect);
+        assert_eq!(core, Rect::from_ltrb(0, 4, 8, 8).unwrap());
+
+        let mut positions: Vec<_> = core.pos_iter().collect();
+        for edge in edges {
+            positions.extend(edge.pos_iter());
+        }
+        positions.sort();
+        let expected: Vec<_> = rect.pos_iter().collect();
+        assert_eq!(positions, expected);
+    }
+
+    #[test]
+    fn split_aligned_too_small() {
+        let rect = Rect::from_ltrb(1, 1, 3, 3).unwrap();
+        let (core, [top, bottom, left, right]) = Block::<4, 4>::split_aligned(rect);
+        assert!(core.is_empty());
+        assert_eq!(top, rect);
+        assert!(bottom.is_empty());
+        assert!(left.is_empty());
+        assert!(right.is_empty());
+    }
+
+    #[test]
+    fn split_aligned_too_narrow() {
+        let rect = Rect::from_ltrb(1, 0, 3, 8).unwrap();
+        let (core, [top, bottom, left, right]) = Block::<4, 4>::split_aligned(rect);
+        assert!(core.is_empty());
+        assert_eq!(top, rect);
+        assert!(bottom.is_empty());
+        assert!(left.is_empty());
+        assert!(right.is_empty());
+    }
 }
@@ -47,9 +47,12 @@ impl<T: Int> Iterator for IterPosColMajor<T> {
 
 impl<T: Int> ExactSizeIterator for IterPosColMajor<T> {
     fn len(&self) -> usize {
-        let remaining_x = self.bounds.right() - self.current.x;
-        let remaining_y = self.bounds.bottom() - self.current.y;
-        remaining_x.to_usize() * remaining_y.to_usize()
+        if self.current.x >= self.bounds.right() {
+            return 0;
+        }
+        let remaining_in_line = self.bounds.bottom() - self.current.y;
+        let remaining_lines = self.bounds.right() - self.current.x - T::ONE;
+        remaining_in_line.to_usize() + remaining_lines.to_usize() * self.bounds.height_usize()
     }
 }
 
@@ -129,7 +132,12 @@ impl Traversal for ColumnMajor {
     /// );
     /// ```
     fn iter_pos<T: Int>(rect: Rect<T>) -> impl Iterator<Item = Pos<T>> {
-        let current = rect.top_left();
+        // Start past the end of an empty rectangle, so that nothing is yielded.
+        let current = if rect.is_empty() {
+            Pos::new(rect.right(), rect.top())
+        } else {
+            rect.top_left()
+        };
         IterPosColMajor {
             current,
             bounds: rect,
@@ -254,6 +262,23 @@ mod tests {
     use super::*;
     use alloc::vec::Vec;
 
+    #[test]
+    fn iter_pos_empty() {
+        let empty = [Rect::from_ltwh(0, 0, 0, 3), Rect::from_ltwh(0, 0, 3, 0)];
+        for rect in empty {
+            assert_eq!(ColumnMajor::iter_pos(rect).count(), 0);
+        }
+    }
+
+    #[test]
+    fn iter_pos_size_hint() {
+        let mut iter = ColumnMajor::iter_pos(Rect::from_ltwh(1, 2, 3, 4));
+        for remaining in (0..=12).rev() {
+            assert_eq!(iter.size_hint(), (remaining, Some(remaining)));
+            iter.next();
+        }
+    }
+
     #[test]
     fn column_major_positions() {
         let rect = Rect::from_ltwh(0, 0, 3, 2);
@@ -331,7 +356,7 @@ mod tests {
             4, 5, 6
         ];
         let size = Size::new(2, 3);
-        assert_eq!(ColumnMajor::slice_aligned(slice, size, 2), &[]);
+        assert_eq!(ColumnMajor::slice_aligned(slice, size, 2), &[] as &[i32]);
     }
 
     #[test]
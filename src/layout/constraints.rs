@@ -0,0 +1,306 @@
+//! Splits a rectangle into children along one axis using a list of constraints.
+//!
+//! Each [`Constraint`] describes the length of one child along the split axis; the children are
+//! laid out back-to-back, starting at the top-left of the parent rectangle.
+//!
+//! Lengths are resolved in two steps:
+//!
+//! 1. [`Constraint::Fixed`], [`Constraint::Percentage`] and [`Constraint::Min`] reserve their
+//!    lengths from the parent;
+//! 2. Any space left over is distributed between [`Constraint::Min`] (with a weight of `1`) and
+//!    [`Constraint::Flex`] (with the given weight) proportionally.
+//!
+//! If the reserved lengths exceed the parent, trailing children are truncated (possibly to empty).
+//! Lengths and weights are summed saturating, so arbitrarily large constraints never overflow.
+//!
+//! ## Examples
+//!
+//! ```rust
+//! use ixy::{Rect, layout::constraints::{self, Constraint}};
+//!
+//! let parent = Rect::from_ltwh(0, 0, 80, 24);
+//! let [header, body, footer] = [Constraint::Fixed(1), Constraint::Flex(1), Constraint::Fixed(1)];
+//! let rows: Vec<_> = constraints::vertical(parent, &[header, body, footer]).collect();
+//! assert_eq!(
+//!     rows,
+//!     &[
+//!         Rect::from_ltwh(0, 0, 80, 1),
+//!         Rect::from_ltwh(0, 1, 80, 22),
+//!         Rect::from_ltwh(0, 23, 80, 1),
+//!     ]
+//! );
+//! ```
+
+use crate::{Rect, int::Int};
+
+/// Describes the length of a child along the split axis.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Constraint {
+    /// Exactly the given number of cells.
+    Fixed(usize),
+
+    /// The given percentage of the parent length, rounded down.
+    ///
+    /// Percentages above `100` are treated as `100`.
+    Percentage(u8),
+
+    /// At least the given number of cells, growing (with a weight of `1`) to fill leftover space.
+    Min(usize),
+
+    /// A share of the leftover space, proportional to the given weight.
+    Flex(usize),
+}
+
+impl Constraint {
+    /// Returns the length reserved by the constraint before leftover space is distributed.
+    const fn reserved(self, total: usize) -> usize {
+        match self {
+            Self::Fixed(len) | Self::Min(len) => len,
+            Self::Percentage(percent) => {
+                // Split `total` so the multiplication cannot overflow.
+                let percent = if percent > 100 { 100 } else { percent as usize };
+                total / 100 * percent + total % 100 * percent / 100
+            }
+            Self::Flex(_) => 0,
+        }
+    }
+
+    /// Returns the weight of the constraint when distributing leftover space.
+    const fn weight(self) -> usize {
+        match self {
+            Self::Fixed(_) | Self::Percentage(_) => 0,
+            Self::Min(_) => 1,
+            Self::Flex(weight) => weight,
+        }
+    }
+}
+
+/// Returns an iterator over the children of `rect`, laid out left-to-right.
+///
+/// Each child spans the full height of `rect`; see the [module-level](self) documentation for how
+/// the widths are resolved.
+///
+/// ## Examples
+///
+/// ```rust
+/// use ixy::{Rect, layout::constraints::{self, Constraint}};
+///
+/// let parent = Rect::from_ltwh(0, 0, 10, 2);
+/// let columns: Vec<_> = constraints::horizontal(
+///     parent,
+///     &[Constraint::Percentage(50), Constraint::Flex(1), Constraint::Flex(1)],
+/// )
+/// .collect();
+/// assert_eq!(
+///     columns,
+///     &[
+///         Rect::from_ltwh(0, 0, 5, 2),
+///         Rect::from_ltwh(5, 0, 2, 2),
+///         Rect::from_ltwh(7, 0, 3, 2),
+///     ]
+/// );
+/// ```
+pub fn horizontal<T: Int>(
+    rect: Rect<T>,
+    constraints: &[Constraint],
+) -> impl Iterator<Item = Rect<T>> {
+    let mut x = rect.left();
+    lengths(rect.width_usize(), constraints).map(move |width| {
+        let child = Rect::from_ltwh(x, rect.top(), width, rect.height_usize());
+        x += T::from_usize(width);
+        child
+    })
+}
+
+/// Returns an iterator over the children of `rect`, laid out top-to-bottom.
+///
+/// Each child spans the full width of `rect`; see the [module-level](self) documentation for how
+/// the heights are resolved.
+///
+/// ## Examples
+///
+/// ```rust
+/// use ixy::{Rect, layout::constraints::{self, Constraint}};
+///
+/// let parent = Rect::from_ltwh(0, 0, 4, 10);
+/// let rows: Vec<_> = constraints::vertical(
+///     parent,
+///     &[Constraint::Min(2), Constraint::Fixed(4)],
+/// )
+/// .collect();
+/// assert_eq!(
+///     rows,
+///     &[Rect::from_ltwh(0, 0, 4, 6), Rect::from_ltwh(0, 6, 4, 4)]
+/// );
+/// ```
+pub fn vertical<T: Int>(
+    rect: Rect<T>,
+    constraints: &[Constraint],
+) -> impl Iterator<Item = Rect<T>> {
+    let mut y = rect.top();
+    lengths(rect.height_usize(), constraints).map(move |height| {
+        let child = Rect::from_ltwh(rect.left(), y, rect.width_usize(), height);
+        y += T::from_usize(height);
+        child
+    })
+}
+
+/// Resolves the length of each constraint, given the `total` length of the parent.
+fn lengths(total: usize, constraints: &[Constraint]) -> impl Iterator<Item = usize> {
+    let reserved = constraints
+        .iter()
+        .fold(0, |sum: usize, c| sum.saturating_add(c.reserved(total)));
+    let weights = constraints
+        .iter()
+        .fold(0, |sum: usize, c| sum.saturating_add(c.weight()));
+    let leftover = total.saturating_sub(reserved);
+
+    // Distribute the leftover using cumulative weights, so rounding never loses a cell.
+    // The share never exceeds `leftover`, as `cumulative` never exceeds `weights`.
+    let share = move |cumulative: usize| {
+        (leftover as u128 * cumulative as u128)
+            .checked_div(weights as u128)
+            .map_or(0, |share| usize::try_from(share).unwrap_or(leftover))
+    };
+
+    let mut cumulative = 0;
+    let mut offset = 0;
+    constraints.iter().map(move |c| {
+        let before = share(cumulative);
+        cumulative = cumulative.saturating_add(c.weight());
+        let after = share(cumulative);
+        let len = c
+            .reserved(total)
+            .saturating_add(after - before)
+            .min(total - offset);
+        offset += len;
+        len
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+
+    use super::*;
+    use alloc::vec::Vec;
+
+    fn widths(total: usize, constraints: &[Constraint]) -> Vec<usize> {
+        horizontal(Rect::from_ltwh(0, 0, total, 1), constraints)
+            .map(|r| r.width_usize())
+            .collect()
+    }
+
+    #[test]
+    fn fixed_only() {
+        assert_eq!(
+            widths(10, &[Constraint::Fixed(3), Constraint::Fixed(4)]),
+            &[3, 4]
+        );
+    }
+
+    #[test]
+    fn percentage_rounds_down() {
+        assert_eq!(
+            widths(9, &[Constraint::Percentage(50), Constraint::Percentage(50)]),
+            &[4, 4]
+        );
+    }
+
+    #[test]
+    fn flex_distributes_all_leftover() {
+        assert_eq!(
+            widths(
+                10,
+                &[
+                    Constraint::Flex(1),
+                    Constraint::Flex(1),
+                    Constraint::Flex(1)
+                ]
+            ),
+            &[3, 3, 4]
+        );
+    }
+
+    #[test]
+    fn flex_weighted() {
+        assert_eq!(
+            widths(12, &[Constraint::Flex(1), Constraint::Flex(2)]),
+            &[4, 8]
+        );
+    }
+
+    #[test]
+    fn flex_zero_weight() {
+        assert_eq!(
+            widths(12, &[Constraint::Flex(0), Constraint::Fixed(2)]),
+            &[0, 2]
+        );
+    }
+
+    #[test]
+    fn min_grows_with_flex() {
+        assert_eq!(
+            widths(10, &[Constraint::Min(2), Constraint::Flex(1)]),
+            &[6, 4]
+        );
+    }
+
+    #[test]
+    fn over_constrained_truncates() {
+        assert_eq!(
+            widths(
+                5,
+                &[
+                    Constraint::Fixed(3),
+                    Constraint::Fixed(3),
+                    Constraint::Fixed(3)
+                ]
+            ),
+            &[3, 2, 0]
+        );
+    }
+
+    #[test]
+    fn percentage_above_100_is_clamped() {
+        assert_eq!(
+            widths(10, &[Constraint::Percentage(250), Constraint::Flex(1)]),
+            &[10, 0]
+        );
+    }
+
+    #[test]
+    fn huge_constraints_saturate() {
+        assert_eq!(
+            widths(5, &[Constraint::Fixed(usize::MAX), Constraint::Fixed(1)]),
+            &[5, 0]
+        );
+        assert_eq!(
+            widths(
+                5,
+                &[
+                    Constraint::Flex(usize::MAX),
+                    Constraint::Flex(usize::MAX),
+                    Constraint::Fixed(1)
+                ]
+            ),
+            &[4, 0, 1]
+        );
+        assert_eq!(Constraint::Percentage(100).reserved(usize::MAX), usize::MAX);
+    }
+
+    #[test]
+    fn vertical_offsets() {
+        let parent = Rect::from_ltwh(2, 3, 4, 5);
+        let rows: Vec<_> = vertical(parent, &[Constraint::Fixed(2), Constraint::Flex(1)]).collect();
+        assert_eq!(
+            rows,
+            &[Rect::from_ltwh(2, 3, 4, 2), Rect::from_ltwh(2, 5, 4, 3)]
+        );
+    }
+
+    #[test]
+    fn empty_constraints() {
+        assert!(widths(5, &[]).is_empty());
+    }
+}
@@ -48,9 +48,12 @@ impl<T: Int> Iterator for IterPosRowMajor<T> {
 
 impl<T: Int> ExactSizeIterator for IterPosRowMajor<T> {
     fn len(&self) -> usize {
-        let remaining_x = self.bounds.right() - self.current.x;
-        let remaining_y = self.bounds.bottom() - self.current.y;
-        remaining_x.to_usize() * remaining_y.to_usize()
+        if self.current.y >= self.bounds.bottom() {
+            return 0;
+        }
+        let remaining_in_line = self.bounds.right() - self.current.x;
+        let remaining_lines = self.bounds.bottom() - self.current.y - T::ONE;
+        remaining_in_line.to_usize() + remaining_lines.to_usize() * self.bounds.width_usize()
     }
 }
 
@@ -131,7 +134,12 @@ impl Traversal for RowMajor {
     /// );
     /// ```
     fn iter_pos<T: Int>(rect: Rect<T>) -> impl Iterator<Item = Pos<T>> {
-        let current = rect.top_left();
+        // Start past the end of an empty rectangle, so that nothing is yielded.
+        let current = if rect.is_empty() {
+            Pos::new(rect.left(), rect.bottom())
+        } else {
+            rect.top_left()
+        };
         IterPosRowMajor {
             current,
             bounds: rect,
@@ -259,6 +267,23 @@ mod tests {
     use super::*;
     use alloc::vec::Vec;
 
+    #[test]
+    fn iter_pos_empty() {
+        let empty = [Rect::from_ltwh(0, 0, 0, 3), Rect::from_ltwh(0, 0, 3, 0)];
+        for rect in empty {
+            assert_eq!(RowMajor::iter_pos(rect).count(), 0);
+        }
+    }
+
+    #[test]
+    fn iter_pos_size_hint() {
+        let mut iter = RowMajor::iter_pos(Rect::from_ltwh(1, 2, 3, 4));
+        for remaining in (0..=12).rev() {
+            assert_eq!(iter.size_hint(), (remaining, Some(remaining)));
+            iter.next();
+        }
+    }
+
     #[test]
     fn row_major_positions() {
         let rect = Rect::from_ltwh(0, 0, 2, 2);
@@ -355,7 +380,7 @@ mod tests {
             4, 5, 6, 7,
         ];
         let size = Size::new(4, 2);
-        assert_eq!(RowMajor::slice_aligned(&slice, size, 2), &[]);
+        assert_eq!(RowMajor::slice_aligned(&slice, size, 2), &[] as &[i32]);
     }
 
     #[test]
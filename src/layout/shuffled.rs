@@ -0,0 +1,240 @@
+use core::iter::FusedIterator;
+
+use crate::{Pos, Rect, Size, int::Int, layout::Traversal};
+
+/// Pseudo-random, but reproducible, traversal order for 2D layouts.
+///
+/// Every position is visited exactly once, in an order determined by `SEED`; the same seed and
+/// rectangle size always produce the same order. The order is generated on the fly (using a
+/// full-period linear congruential generator), so no permutation is allocated.
+///
+/// This is useful for dithering, progressive rendering, or randomizing the update order of a
+/// simulation. The order is _not_ suitable for cryptographic or statistical purposes.
+///
+/// ## Examples
+///
+/// ```rust
+/// use ixy::{Pos, Rect, layout::{RowMajor, Shuffled, Traversal}};
+///
+/// let rect = Rect::from_ltwh(0, 0, 4, 3);
+/// let mut shuffled: Vec<_> = Shuffled::<42>::iter_pos(rect).collect();
+/// assert_ne!(shuffled, RowMajor::iter_pos(rect).collect::<Vec<_>>());
+///
+/// // Same seed, same order.
+/// assert!(Shuffled::<42>::iter_pos(rect).eq(shuffled.iter().copied()));
+///
+/// // Every position is visited exactly once.
+/// shuffled.sort();
+/// assert_eq!(shuffled, RowMajor::iter_pos(rect).collect::<Vec<_>>());
+/// ```
+#[derive(Clone, Copy)]
+pub enum Shuffled<const SEED: u64 = 0> {}
+
+/// Multiplier for the generator; any value `≡ 1 (mod 4)` gives a full period.
+const MULTIPLIER: u64 = 0x5851_F42D_4C95_7F2D;
+
+/// Odd multiplier used to scramble the output of the generator.
+const SCRAMBLE: u64 = 0x9E37_79B9_7F4A_7C15;
+
+/// Iterator over a pseudo-random permutation of `0..len`.
+///
+/// Steps through all values of `0..2^k` (where `2^k >= len`) with a full-period LCG, scrambles
+/// each with a bijection, and skips values that are out of range ("cycle-walking").
+struct Permutation {
+    len: u64,
+    mask: u64,
+    shift: u32,
+    increment: u64,
+    state: u64,
+    remaining: u64,
+}
+
+impl Permutation {
+    fn new(len: usize, seed: u64) -> Self {
+        let len = len as u64;
+        let mask = len.checked_next_power_of_two().map_or(u64::MAX, |m| m - 1);
+        let bits = u64::BITS - mask.leading_zeros();
+        Self {
+            len,
+            mask,
+            shift: (bits / 2).max(1),
+            increment: (seed << 1) | 1,
+            state: seed.wrapping_mul(SCRAMBLE) & mask,
+            remaining: len,
+        }
+    }
+
+    /// Maps the state to an output value, bijectively within `0..=mask`.
+    const fn scramble(&self, mut x: u64) -> u64 {
+        x ^= x >> self.shift;
+        x = x.wrapping_mul(SCRAMBLE) & self.mask;
+        x ^= x >> self.shift;
+        x
+    }
+}
+
+impl Iterator for Permutation {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        loop {
+            let value = self.scramble(self.state);
+            self.state = self
+                .state
+                .wrapping_mul(MULTIPLIER)
+                .wrapping_add(self.increment)
+                & self.mask;
+            if value < self.len {
+                self.remaining -= 1;
+                return usize::try_from(value).ok();
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+}
+
+impl ExactSizeIterator for Permutation {
+    fn len(&self) -> usize {
+        usize::try_from(self.remaining).unwrap_or(usize::MAX)
+    }
+}
+
+impl FusedIterator for Permutation {}
+
+impl<const SEED: u64> Traversal for Shuffled<SEED> {
+    /// Returns an iterator over the positions in the specified rectangle.
+    ///
+    /// The positions are returned in a pseudo-random order determined by `SEED`.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use ixy::{Rect, layout::{Shuffled, Traversal}};
+    ///
+    /// let rect = Rect::from_ltwh(0, 0, 16, 16);
+    /// assert_eq!(Shuffled::<7>::iter_pos(rect).count(), 256);
+    /// ```
+    fn iter_pos<T: Int>(rect: Rect<T>) -> impl Iterator<Item = Pos<T>> {
+        let width = rect.width_usize();
+        Permutation::new(rect.area(), SEED).map(move |i| {
+            let x = rect.left() + T::from_usize(i % width);
+            let y = rect.top() + T::from_usize(i / width);
+            Pos::new(x, y)
+        })
+    }
+
+    /// Returns an iterator over blocks of the specified size within the rectangle.
+    ///
+    /// The blocks are returned in a pseudo-random order determined by `SEED`, and blocks that
+    /// would be partially outside the rectangle are not yielded.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use ixy::{Rect, Size, layout::{Shuffled, Traversal}};
+    ///
+    /// let rect = Rect::from_ltwh(0, 0, 5, 4);
+    /// let mut blocks: Vec<_> = Shuffled::<7>::iter_rect(rect, Size::new(2, 2)).collect();
+    /// blocks.sort_by_key(|b| b.top_left());
+    /// assert_eq!(
+    ///     blocks,
+    ///     &[
+    ///         Rect::from_ltwh(0, 0, 2, 2),
+    ///         Rect::from_ltwh(2, 0, 2, 2),
+    ///         Rect::from_ltwh(0, 2, 2, 2),
+    ///         Rect::from_ltwh(2, 2, 2, 2),
+    ///     ]
+    /// );
+    /// ```
+    fn iter_rect<T: Int>(rect: Rect<T>, size: Size) -> impl Iterator<Item = Rect<T>> {
+        let columns = rect.width_usize().checked_div(size.width).unwrap_or(0);
+        let rows = rect.height_usize().checked_div(size.height).unwrap_or(0);
+        Permutation::new(columns * rows, SEED).map(move |i| {
+            let x = rect.left() + T::from_usize(i % columns * size.width);
+            let y = rect.top() + T::from_usize(i / columns * size.height);
+            Rect::from_tl_size(Pos::new(x, y), size)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+
+    use super::*;
+    use crate::layout::RowMajor;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn permutation_visits_all_once() {
+        for len in [0, 1, 2, 3, 7, 8, 9, 100, 1000] {
+            for seed in [0, 1, 42, u64::MAX] {
+                let mut values: Vec<_> = Permutation::new(len, seed).collect();
+                assert_eq!(values.len(), len);
+                values.sort_unstable();
+                assert!(values.iter().copied().eq(0..len), "len={len} seed={seed}");
+            }
+        }
+    }
+
+    #[test]
+    fn permutation_len() {
+        let mut iter = Permutation::new(10, 3);
+        assert_eq!(iter.len(), 10);
+        iter.next();
+        assert_eq!(iter.len(), 9);
+        assert_eq!(iter.count(), 9);
+    }
+
+    #[test]
+    fn different_seeds_differ() {
+        let rect = Rect::from_ltwh(0, 0, 8, 8);
+        let a: Vec<_> = Shuffled::<1>::iter_pos(rect).collect();
+        let b: Vec<_> = Shuffled::<2>::iter_pos(rect).collect();
+        assert_ne!(a, b);
+    }
+
+    #[test]
+    fn iter_pos_offset_rect() {
+        let rect = Rect::from_ltwh(-3, 5, 3, 2);
+        let mut positions: Vec<_> = Shuffled::<9>::iter_pos(rect).collect();
+        positions.sort();
+        assert_eq!(positions, RowMajor::iter_pos(rect).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn iter_pos_empty() {
+        let rect = Rect::from_ltwh(0, 0, 0, 5);
+        assert_eq!(Shuffled::<0>::iter_pos(rect).count(), 0);
+    }
+
+    #[test]
+    fn iter_rect_skips_partial_blocks() {
+        let rect = Rect::from_ltwh(1, 1, 7, 5);
+        let size = Size::new(3, 2);
+        let mut blocks: Vec<_> = Shuffled::<5>::iter_rect(rect, size).collect();
+        blocks.sort_by_key(Rect::top_left);
+        assert_eq!(
+            blocks,
+            &[
+                Rect::from_ltwh(1, 1, 3, 2),
+                Rect::from_ltwh(4, 1, 3, 2),
+                Rect::from_ltwh(1, 3, 3, 2),
+                Rect::from_ltwh(4, 3, 3, 2),
+            ]
+        );
+    }
+
+    #[test]
+    fn iter_rect_empty_size() {
+        let rect = Rect::from_ltwh(0, 0, 4, 4);
+        assert_eq!(Shuffled::<0>::iter_rect(rect, Size::new(0, 2)).count(), 0);
+    }
+}
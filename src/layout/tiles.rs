@@ -0,0 +1,199 @@
+//! Cache-friendly traversal of large rectangles, one tile at a time.
+//!
+//! Visiting a large rectangle row-by-row touches every row of the underlying data before coming
+//! back to the next column, which can evict data from the cache before it is reused. Splitting the
+//! rectangle into tiles that fit in the cache avoids that, but picking a good tile size by hand is
+//! error-prone; [`tile_size`] picks one from the size of an element and a cache budget.
+//!
+//! ## Examples
+//!
+//! ```rust
+//! use ixy::{Rect, Size, layout::{RowMajor, tiles}};
+//!
+//! let rect = Rect::from_ltwh(0, 0, 1000, 1000);
+//! let tile = tiles::tile_size(size_of::<u32>(), tiles::L1_CACHE_BUDGET);
+//! assert_eq!(tile, Size::new(128, 64));
+//!
+//! let area: usize = tiles::iter_tiles::<RowMajor, _>(rect, tile).map(|t| t.area()).sum();
+//! assert_eq!(area, rect.area());
+//! ```
+
+use crate::{Rect, Size, int::Int, layout::Traversal};
+
+/// A conservative cache budget, in bytes, that fits in the L1 data cache of most CPUs.
+pub const L1_CACHE_BUDGET: usize = 32 * 1024;
+
+/// Returns a tile size whose elements fit within `cache_budget` bytes.
+///
+/// Both dimensions are powers of two, and the tile is either square or twice as wide as it is
+/// tall (wider tiles favour row-major data). An `element_size` of `0` is treated as `1`, and the
+/// tile is never smaller than `1x1`, even if a single element exceeds the budget.
+///
+/// ## Examples
+///
+/// ```rust
+/// use ixy::{Size, layout::tiles};
+///
+/// assert_eq!(tiles::tile_size(1, 4096), Size::new(64, 64));
+/// assert_eq!(tiles::tile_size(4, 4096), Size::new(32, 32));
+/// assert_eq!(tiles::tile_size(8, 4096), Size::new(32, 16));
+/// assert_eq!(tiles::tile_size(8192, 4096), Size::new(1, 1));
+/// ```
+#[must_use]
+pub const fn tile_size(element_size: usize, cache_budget: usize) -> Size {
+    let element_size = if element_size == 0 { 1 } else { element_size };
+    let elements = cache_budget / element_size;
+    let mut side = 1;
+    while (side * 2) * (side * 2) <= elements {
+        side *= 2;
+    }
+    if side * side * 2 <= elements {
+        Size::new(side * 2, side)
+    } else {
+        Size::new(side, side)
+    }
+}
+
+/// Returns an iterator over tiles of the given size that cover `rect`.
+///
+/// Tiles are laid out starting at the top-left of `rect` and visited in the order defined by the
+/// traversal `L`. Unlike [`Traversal::iter_rect`], tiles that would be partially outside `rect`
+/// are clipped to it rather than skipped, so every position in `rect` is in exactly one tile.
+///
+/// ## Panics
+///
+/// Panics if `tile` has a width or height of `0`.
+///
+/// ## Examples
+///
+/// ```rust
+/// use ixy::{Rect, Size, layout::{RowMajor, tiles}};
+///
+/// let rect = Rect::from_ltwh(0, 0, 5, 3);
+/// let tiles: Vec<_> = tiles::iter_tiles::<RowMajor, _>(rect, Size::new(4, 2)).collect();
+/// assert_eq!(
+///     tiles,
+///     &[
+///         Rect::from_ltwh(0, 0, 4, 2),
+///         Rect::from_ltwh(4, 0, 1, 2),
+///         Rect::from_ltwh(0, 2, 4, 1),
+///         Rect::from_ltwh(4, 2, 1, 1),
+///     ]
+/// );
+/// ```
+pub fn iter_tiles<L: Traversal, T: Int>(
+    rect: Rect<T>,
+    tile: Size,
+) -> impl Iterator<Item = Rect<T>> {
+    assert!(
+        tile.width > 0 && tile.height > 0,
+        "tile size must not be empty"
+    );
+    let covering = Rect::from_tl_size(
+        rect.top_left(),
+        Size::new(
+            rect.width_usize().div_ceil(tile.width) * tile.width,
+            rect.height_usize().div_ceil(tile.height) * tile.height,
+        ),
+    );
+    L::iter_rect(covering, tile).map(move |t| t.intersect(rect))
+}
+
+/// Returns an iterator over cache-sized tiles that cover `rect`.
+///
+/// Equivalent to [`iter_tiles`] with a tile size from [`tile_size`].
+///
+/// ## Examples
+///
+/// ```rust
+/// use ixy::{Rect, layout::{RowMajor, tiles}};
+///
+/// let rect = Rect::from_ltwh(0, 0, 100, 100);
+/// let count = tiles::iter_cache_tiles::<RowMajor, _>(rect, 4, 1024).count();
+/// assert_eq!(count, 49);
+/// ```
+pub fn iter_cache_tiles<L: Traversal, T: Int>(
+    rect: Rect<T>,
+    element_size: usize,
+    cache_budget: usize,
+) -> impl Iterator<Item = Rect<T>> {
+    iter_tiles::<L, T>(rect, tile_size(element_size, cache_budget))
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+
+    use super::*;
+    use crate::layout::{ColumnMajor, RowMajor};
+    use alloc::vec::Vec;
+
+    #[test]
+    fn tile_size_fits_budget() {
+        for element_size in [1, 2, 3, 4, 8, 12, 16, 64] {
+            for budget in [64, 1000, 4096, L1_CACHE_BUDGET, 256 * 1024] {
+                let tile = tile_size(element_size, budget);
+                assert!(
+                    tile.area() * element_size <= budget,
+                    "{element_size} {budget}"
+                );
+                assert!(
+                    tile.area() * element_size * 2 > budget,
+                    "{element_size} {budget}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn tile_size_zero() {
+        assert_eq!(tile_size(0, 16), Size::new(4, 4));
+        assert_eq!(tile_size(4, 0), Size::new(1, 1));
+    }
+
+    #[test]
+    fn iter_tiles_aligned() {
+        let rect = Rect::from_ltwh(2, 2, 4, 4);
+        let tiles: Vec<_> = iter_tiles::<RowMajor, _>(rect, Size::new(2, 2)).collect();
+        let expected: Vec<_> = RowMajor::iter_rect(rect, Size::new(2, 2)).collect();
+        assert_eq!(tiles, expected);
+    }
+
+    #[test]
+    fn iter_tiles_column_major_clipped() {
+        let rect = Rect::from_ltwh(-1, -1, 3, 3);
+        let tiles: Vec<_> = iter_tiles::<ColumnMajor, _>(rect, Size::new(2, 2)).collect();
+        assert_eq!(
+            tiles,
+            &[
+                Rect::from_ltwh(-1, -1, 2, 2),
+                Rect::from_ltwh(-1, 1, 2, 1),
+                Rect::from_ltwh(1, -1, 1, 2),
+                Rect::from_ltwh(1, 1, 1, 1),
+            ]
+        );
+    }
+
+    #[test]
+    fn iter_tiles_covers_rect_exactly() {
+        let rect = Rect::from_ltwh(3, 5, 17, 9);
+        let mut positions: Vec<_> = iter_tiles::<RowMajor, _>(rect, Size::new(4, 4))
+            .flat_map(RowMajor::iter_pos)
+            .collect();
+        positions.sort();
+        let expected: Vec<_> = rect.pos_iter().collect();
+        assert_eq!(positions, expected);
+    }
+
+    #[test]
+    fn iter_tiles_empty_rect() {
+        let rect = Rect::from_ltwh(3, 5, 0, 9);
+        assert_eq!(iter_tiles::<RowMajor, _>(rect, Size::new(4, 4)).count(), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "tile size must not be empty")]
+    fn iter_tiles_empty_tile_panics() {
+        let _ = iter_tiles::<RowMajor, i32>(Rect::from_ltwh(0, 0, 1, 1), Size::new(0, 1));
+    }
+}
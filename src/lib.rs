@@ -30,11 +30,26 @@ pub mod ops;
 
 pub(crate) mod internal;
 
+mod align;
+pub use align::*;
+
+mod direction;
+pub use direction::*;
+
+mod insets;
+pub use insets::*;
+
 mod pos;
 pub use pos::*;
 
 mod rect;
 pub use rect::*;
 
+mod segment;
+pub use segment::*;
+
 mod size;
 pub use size::*;
+
+mod span;
+pub use span::*;
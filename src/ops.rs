@@ -1,4 +1,14 @@
 //! Operations on 2D geometric types.
 
+pub mod cell;
+pub mod circle;
+pub mod collide;
 pub mod distance;
 pub mod line;
+pub mod path;
+pub mod rects;
+pub mod rounded_rect;
+pub mod route;
+
+#[cfg(feature = "rand")]
+pub mod sample;
@@ -0,0 +1,177 @@
+//! Mapping between world coordinates and cell (tile) coordinates.
+//!
+//! A world is divided into cells of a fixed [`Size`], where cell `(0, 0)` covers the world
+//! positions from `(0, 0)` (inclusive) to `(cell.width, cell.height)` (exclusive).
+//!
+//! All conversions use Euclidean division, so negative world coordinates are rounded towards
+//! negative infinity (e.g. with a cell width of `16`, world x `-1` is in cell x `-1`, not `0`).
+//!
+//! ## Panics
+//!
+//! Every function in this module panics if the cell size has a width or height of `0`.
+
+use crate::{Pos, Rect, Size, int::Int, internal};
+
+/// Returns the cell that contains the given world position.
+///
+/// ## Examples
+///
+/// ```rust
+/// use ixy::{Pos, Size, ops::cell};
+///
+/// let tile = Size::new(16, 8);
+/// assert_eq!(cell::pos_to_cell(Pos::new(17, 7), tile), Pos::new(1, 0));
+/// assert_eq!(cell::pos_to_cell(Pos::new(-1, -8), tile), Pos::new(-1, -1));
+/// ```
+#[must_use]
+pub fn pos_to_cell<T: Int>(pos: Pos<T>, cell: Size) -> Pos<T> {
+    let (w, h) = cell_size(cell);
+    Pos::new(pos.x.div_euclid(w), pos.y.div_euclid(h))
+}
+
+/// Returns the smallest rectangle of cells that covers the given world rectangle.
+///
+/// An empty rectangle maps to an empty rectangle at the cell containing its top-left corner.
+///
+/// ## Examples
+///
+/// ```rust
+/// use ixy::{Rect, Size, ops::cell};
+///
+/// let tile = Size::new(16, 16);
+/// let world = Rect::from_ltwh(-4, 8, 24, 16);
+/// assert_eq!(cell::rect_to_cells(world, tile), Rect::from_ltwh(-1, 0, 3, 2));
+/// ```
+#[must_use]
+pub fn rect_to_cells<T: Int>(rect: Rect<T>, cell: Size) -> Rect<T> {
+    let top_left = pos_to_cell(rect.top_left(), cell);
+    if rect.is_empty() {
+        return Rect::from_ltrb_unchecked(top_left.x, top_left.y, top_left.x, top_left.y);
+    }
+    let (w, h) = cell_size(cell);
+    let r = internal::div_ceil_euclid(rect.right(), w);
+    let b = internal::div_ceil_euclid(rect.bottom(), h);
+    Rect::from_ltrb_unchecked(top_left.x, top_left.y, r, b)
+}
+
+/// Returns the world position of the top-left corner of the given cell.
+///
+/// ## Examples
+///
+/// ```rust
+/// use ixy::{Pos, Size, ops::cell};
+///
+/// let tile = Size::new(16, 8);
+/// assert_eq!(cell::cell_to_pos(Pos::new(-1, 2), tile), Pos::new(-16, 16));
+/// ```
+#[must_use]
+pub fn cell_to_pos<T: Int>(cell_pos: Pos<T>, cell: Size) -> Pos<T> {
+    let (w, h) = cell_size(cell);
+    Pos::new(cell_pos.x * w, cell_pos.y * h)
+}
+
+/// Returns the world rectangle covered by the given cell.
+///
+/// ## Examples
+///
+/// ```rust
+/// use ixy::{Pos, Rect, Size, ops::cell};
+///
+/// let tile = Size::new(16, 8);
+/// assert_eq!(cell::cell_to_rect(Pos::new(1, 1), tile), Rect::from_ltwh(16, 8, 16, 8));
+/// ```
+#[must_use]
+pub fn cell_to_rect<T: Int>(cell_pos: Pos<T>, cell: Size) -> Rect<T> {
+    Rect::from_tl_size(cell_to_pos(cell_pos, cell), cell)
+}
+
+/// Returns the world rectangle covered by the given rectangle of cells.
+///
+/// ## Examples
+///
+/// ```rust
+/// use ixy::{Rect, Size, ops::cell};
+///
+/// let tile = Size::new(16, 16);
+/// let cells = Rect::from_ltwh(-1, 0, 3, 2);
+/// assert_eq!(cell::cells_to_rect(cells, tile), Rect::from_ltwh(-16, 0, 48, 32));
+/// ```
+#[must_use]
+pub fn cells_to_rect<T: Int>(cells: Rect<T>, cell: Size) -> Rect<T> {
+    let top_left = cell_to_pos(cells.top_left(), cell);
+    let bottom_right = cell_to_pos(cells.bottom_right(), cell);
+    Rect::from_ltrb_unchecked(top_left.x, top_left.y, bottom_right.x, bottom_right.y)
+}
+
+/// Returns the cell size as `T`, asserting that it is not empty.
+fn cell_size<T: Int>(cell: Size) -> (T, T) {
+    assert!(
+        cell.width > 0 && cell.height > 0,
+        "cell size must not be empty"
+    );
+    (T::from_usize(cell.width), T::from_usize(cell.height))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pos_to_cell_positive() {
+        let tile = Size::new(4, 4);
+        assert_eq!(pos_to_cell(Pos::new(0, 0), tile), Pos::new(0, 0));
+        assert_eq!(pos_to_cell(Pos::new(3, 3), tile), Pos::new(0, 0));
+        assert_eq!(pos_to_cell(Pos::new(4, 7), tile), Pos::new(1, 1));
+    }
+
+    #[test]
+    fn pos_to_cell_negative() {
+        let tile = Size::new(4, 4);
+        assert_eq!(pos_to_cell(Pos::new(-1, -4), tile), Pos::new(-1, -1));
+        assert_eq!(pos_to_cell(Pos::new(-5, -5), tile), Pos::new(-2, -2));
+    }
+
+    #[test]
+    fn pos_to_cell_unsigned() {
+        let tile = Size::new(10, 10);
+        assert_eq!(pos_to_cell(Pos::<u16>::new(25, 9), tile), Pos::new(2, 0));
+    }
+
+    #[test]
+    fn rect_to_cells_aligned() {
+        let tile = Size::new(4, 4);
+        let rect = Rect::from_ltwh(4, 8, 8, 4);
+        assert_eq!(rect_to_cells(rect, tile), Rect::from_ltwh(1, 2, 2, 1));
+    }
+
+    #[test]
+    fn rect_to_cells_unaligned_negative() {
+        let tile = Size::new(4, 4);
+        let rect = Rect::from_ltwh(-5, -1, 2, 2);
+        assert_eq!(rect_to_cells(rect, tile), Rect::from_ltwh(-2, -1, 2, 2));
+    }
+
+    #[test]
+    fn rect_to_cells_empty() {
+        let tile = Size::new(4, 4);
+        let rect = Rect::from_ltwh(5, 5, 0, 0);
+        assert_eq!(rect_to_cells(rect, tile), Rect::from_ltwh(1, 1, 0, 0));
+    }
+
+    #[test]
+    fn round_trip() {
+        let tile = Size::new(3, 5);
+        let cell = Pos::new(-2, 7);
+        assert_eq!(pos_to_cell(cell_to_pos(cell, tile), tile), cell);
+        assert_eq!(
+            rect_to_cells(cell_to_rect(cell, tile), tile),
+            Rect::from_ltwh(-2, 7, 1, 1)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "cell size must not be empty")]
+    fn empty_cell_size_panics() {
+        let _ = pos_to_cell(Pos::new(1, 1), Size::new(0, 1));
+    }
+}
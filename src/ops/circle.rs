@@ -0,0 +1,528 @@
+//! Circle operations.
+//!
+//! Circles are rasterized one [`Octant`] at a time, so partial rings (such as vision cones) can be
+//! iterated without generating the full circle and filtering it.
+//!
+//! Octants are numbered _clockwise_ (on screen, where y increases downwards) starting at the
+//! positive x-axis, and each octant is named after the compass direction of its center:
+//!
+//! ```txt
+//!        NNW | NNE
+//!    WNW  \  |  /  ENE
+//!   ------- O -------
+//!    WSW  /  |  \  ESE
+//!        SSW | SSE
+//! ```
+//!
+//! Positions within an octant are yielded in clockwise order. Each octant includes the boundary
+//! position it starts at, but not the one it ends at, so consecutive octants never yield the same
+//! position twice.
+//!
+//! ## Panics
+//!
+//! Every function in this module works for any radius up to `u64::MAX` (the error terms are
+//! computed in `u128`), and panics for larger radii, which are only possible with `i128`.
+
+use core::iter::FusedIterator;
+
+use crate::{Pos, Span, int::SignedInt};
+
+/// One eighth of a circle, spanning 45 degrees.
+///
+/// See the [module-level](self) documentation for the numbering and naming of octants.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Octant {
+    /// From the positive x-axis (east) to the south-east diagonal.
+    Ese,
+
+    /// From the south-east diagonal to the positive y-axis (south).
+    Sse,
+
+    /// From the positive y-axis (south) to the south-west diagonal.
+    Ssw,
+
+    /// From the south-west diagonal to the negative x-axis (west).
+    Wsw,
+
+    /// From the negative x-axis (west) to the north-west diagonal.
+    Wnw,
+
+    /// From the north-west diagonal to the negative y-axis (north).
+    Nnw,
+
+    /// From the negative y-axis (north) to the north-east diagonal.
+    Nne,
+
+    /// From the north-east diagonal to the positive x-axis (east).
+    Ene,
+}
+
+impl Octant {
+    /// All octants, in clockwise order starting at [`Octant::Ese`].
+    pub const ALL: [Self; 8] = [
+        Self::Ese,
+        Self::Sse,
+        Self::Ssw,
+        Self::Wsw,
+        Self::Wnw,
+        Self::Nnw,
+        Self::Nne,
+        Self::Ene,
+    ];
+
+    /// Returns the next octant in clockwise order.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use ixy::ops::circle::Octant;
+    ///
+    /// assert_eq!(Octant::Ese.next(), Octant::Sse);
+    /// assert_eq!(Octant::Ene.next(), Octant::Ese);
+    /// ```
+    #[must_use]
+    pub const fn next(self) -> Self {
+        Self::ALL[(self as usize + 1) % 8]
+    }
+
+    /// Returns `true` if positions are generated from the axis towards the diagonal.
+    const fn starts_at_axis(self) -> bool {
+        (self as usize).is_multiple_of(2)
+    }
+
+    /// Maps a position from the first octant (`x >= y >= 0`) to this octant.
+    fn map<T: SignedInt>(self, x: T, y: T) -> Pos<T> {
+        match self {
+            Self::Ese => Pos::new(x, y),
+            Self::Sse => Pos::new(y, x),
+            Self::Ssw => Pos::new(-y, x),
+            Self::Wsw => Pos::new(-x, y),
+            Self::Wnw => Pos::new(-x, -y),
+            Self::Nnw => Pos::new(-y, -x),
+            Self::Nne => Pos::new(y, -x),
+            Self::Ene => Pos::new(x, -y),
+        }
+    }
+}
+
+/// Returns the square of `|n|` as a `u128`, so it cannot overflow `T`.
+fn square<T: SignedInt>(n: T) -> u128 {
+    let n = n.abs_diff_u128(T::ZERO);
+    n.checked_mul(n).expect("radius out of range")
+}
+
+/// Converts a non-negative value no larger than the radius back to `T`.
+fn from_u128<T: SignedInt>(n: u128) -> T {
+    T::ZERO
+        .checked_add_u128(n)
+        .expect("value bounded by the radius")
+}
+
+/// Returns the x-coordinate of the circle at `y`, for the first octant, rounded to the nearest.
+fn first_octant_x<T: SignedInt>(radius: T, y: T) -> T {
+    let v = square(radius).saturating_sub(square(y));
+    let x = v.isqrt();
+    from_u128(if v > x * x + x { x + 1 } else { x })
+}
+
+/// Returns the largest `y` for which the first octant still satisfies `y <= x`.
+fn first_octant_end<T: SignedInt>(radius: T) -> T {
+    let mut y = from_u128((square(radius) / 2).isqrt());
+    while y + T::ONE <= first_octant_x(radius, y + T::ONE) {
+        y += T::ONE;
+    }
+    while y > first_octant_x(radius, y) {
+        y -= T::ONE;
+    }
+    y
+}
+
+/// Calculates positions along the outline of a circle.
+///
+/// Positions are yielded clockwise, starting at `center + (radius, 0)`, and each position is
+/// yielded exactly once. A `radius` of `0` yields only `center`.
+///
+/// ## Examples
+///
+/// ```rust
+/// use ixy::{Pos, ops::circle};
+///
+/// let points: Vec<_> = circle::circle(Pos::new(0, 0), 1).collect();
+/// assert_eq!(
+///     points,
+///     &[Pos::new(1, 0), Pos::new(0, 1), Pos::new(-1, 0), Pos::new(0, -1)]
+/// );
+/// ```
+pub fn circle<T: SignedInt>(center: Pos<T>, radius: T) -> impl Iterator<Item = Pos<T>> {
+    arc(center, radius, Octant::Ese, Octant::Ene)
+}
+
+/// Calculates positions along the outline of a circle, within the given octants.
+///
+/// Octants are visited clockwise from `start` up to _and including_ `end`; if `end` comes before
+/// `start`, the arc wraps around the positive x-axis. If `start == end`, only that octant is
+/// visited.
+///
+/// ## Examples
+///
+/// ```rust
+/// use ixy::{Pos, ops::circle::{self, Octant}};
+///
+/// // The bottom half of a circle, from east to west.
+/// let points: Vec<_> = circle::arc(Pos::new(0, 0), 2, Octant::Ese, Octant::Wsw).collect();
+/// assert_eq!(
+///     points,
+///     &[
+///         Pos::new(2, 0),
+///         Pos::new(2, 1),
+///         Pos::new(1, 2),
+///         Pos::new(0, 2),
+///         Pos::new(-1, 2),
+///         Pos::new(-2, 1),
+///     ]
+/// );
+/// ```
+pub fn arc<T: SignedInt>(
+    center: Pos<T>,
+    radius: T,
+    start: Octant,
+    end: Octant,
+) -> impl Iterator<Item = Pos<T>> {
+    let count = (end as usize + 8 - start as usize) % 8 + 1;
+    let mut current = start;
+    (0..count).flat_map(move |_| {
+        let iter = octant(center, radius, current);
+        current = current.next();
+        iter
+    })
+}
+
+/// Calculates positions along the outline of a circle, within a single octant.
+///
+/// The starting boundary of the octant is included, but the ending boundary is not; see the
+/// [module-level](self) documentation.
+///
+/// ## Examples
+///
+/// ```rust
+/// use ixy::{Pos, ops::circle::{self, Octant}};
+///
+/// let points: Vec<_> = circle::octant(Pos::new(10, 10), 3, Octant::Ese).collect();
+/// assert_eq!(points, &[Pos::new(13, 10), Pos::new(13, 11)]);
+/// ```
+pub fn octant<T: SignedInt>(center: Pos<T>, radius: T, octant: Octant) -> OctantIter<T> {
+    let last = first_octant_end(radius);
+    let (next, end) = if radius == T::ZERO {
+        // A single point, yielded by the first octant only.
+        if octant == Octant::Ese {
+            (T::ZERO, T::ONE)
+        } else {
+            (T::ZERO, T::ZERO)
+        }
+    } else if octant.starts_at_axis() {
+        // Exclude the diagonal, which belongs to the next octant.
+        let end = if first_octant_x(radius, last) == last {
+            last
+        } else {
+            last + T::ONE
+        };
+        (T::ZERO, end)
+    } else {
+        // Walk from the diagonal back to (but excluding) the axis.
+        (last, T::ZERO)
+    };
+    OctantIter {
+        center,
+        radius,
+        octant,
+        next,
+        end,
+    }
+}
+
+/// An iterator over positions along the outline of a circle, within a single octant.
+///
+/// Created by [`octant`].
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct OctantIter<T> {
+    center: Pos<T>,
+    radius: T,
+    octant: Octant,
+    next: T,
+    end: T,
+}
+
+impl<T: SignedInt> Iterator for OctantIter<T> {
+    type Item = Pos<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.next == self.end {
+            return None;
+        }
+        let y = self.next;
+        if self.octant.starts_at_axis() {
+            self.next += T::ONE;
+        } else {
+            self.next -= T::ONE;
+        }
+        let x = first_octant_x(self.radius, y);
+        Some(self.center + self.octant.map(x, y))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+}
+
+impl<T: SignedInt> ExactSizeIterator for OctantIter<T> {
+    fn len(&self) -> usize {
+        (self.end - self.next).abs().to_usize()
+    }
+}
+
+impl<T: SignedInt> FusedIterator for OctantIter<T> {}
+
+/// Calculates the rows of a filled circle, as spans of `(y, x_start..x_end)`.
+///
+/// Rows are yielded from top to bottom, and each half-open [`Span`] covers exactly the outline
+/// positions of [`circle`] on that row and everything between them, so a filled circle can be
+/// drawn with one slice fill per row. A `radius` of `0` yields only `center`.
+///
+/// ## Examples
+///
+/// ```rust
+/// use ixy::{Pos, Span, ops::circle};
+///
+/// let spans: Vec<_> = circle::filled_spans(Pos::new(0, 0), 1).collect();
+/// assert_eq!(
+///     spans,
+///     &[(-1, Span::new(0, 1)), (0, Span::new(-1, 2)), (1, Span::new(0, 1))]
+/// );
+/// ```
+pub fn filled_spans<T: SignedInt>(center: Pos<T>, radius: T) -> FilledSpans<T> {
+    FilledSpans {
+        center,
+        radius,
+        last: first_octant_end(radius),
+        next: -radius,
+    }
+}
+
+/// An iterator over the rows of a filled circle.
+///
+/// Created by [`filled_spans`].
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct FilledSpans<T> {
+    center: Pos<T>,
+    radius: T,
+    last: T,
+    next: T,
+}
+
+impl<T: SignedInt> FilledSpans<T> {
+    /// Returns the largest `|x|` of the outline on row `y`.
+    fn half_width(&self, y: T) -> T {
+        let y = y.abs();
+        if y <= self.last {
+            first_octant_x(self.radius, y)
+        } else {
+            // The largest x whose first-octant outline position rounds to at least `y`.
+            let v = square(self.radius) - square(y) + (y - T::ONE).abs_diff_u128(T::ZERO);
+            from_u128::<T>(v.isqrt()).min(self.last)
+        }
+    }
+}
+
+impl<T: SignedInt> Iterator for FilledSpans<T> {
+    type Item = (T, Span<T>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.next > self.radius {
+            return None;
+        }
+        let y = self.next;
+        self.next += T::ONE;
+        let half = self.half_width(y);
+        let span = Span::new(self.center.x - half, self.center.x + half + T::ONE);
+        Some((self.center.y + y, span))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+}
+
+impl<T: SignedInt> ExactSizeIterator for FilledSpans<T> {
+    fn len(&self) -> usize {
+        if self.next > self.radius {
+            0
+        } else {
+            (self.radius - self.next + T::ONE).to_usize()
+        }
+    }
+}
+
+impl<T: SignedInt> FusedIterator for FilledSpans<T> {}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+
+    use super::*;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn radius_zero() {
+        let points: Vec<_> = circle(Pos::new(3, 4), 0).collect();
+        assert_eq!(points, &[Pos::new(3, 4)]);
+    }
+
+    #[test]
+    fn radius_one() {
+        let points: Vec<_> = circle(Pos::new(0, 0), 1).collect();
+        assert_eq!(
+            points,
+            &[
+                Pos::new(1, 0),
+                Pos::new(0, 1),
+                Pos::new(-1, 0),
+                Pos::new(0, -1)
+            ]
+        );
+    }
+
+    #[test]
+    fn full_circle_has_no_duplicates() {
+        for radius in 0..20 {
+            let mut points: Vec<_> = circle(Pos::new(0, 0), radius).collect();
+            let len = points.len();
+            points.sort();
+            points.dedup();
+            assert_eq!(points.len(), len, "radius {radius}");
+        }
+    }
+
+    #[test]
+    fn full_circle_is_symmetric() {
+        let points: Vec<_> = circle(Pos::new(0, 0), 7).collect();
+        for p in &points {
+            assert!(points.contains(&Pos::new(p.y, p.x)));
+            assert!(points.contains(&Pos::new(-p.x, p.y)));
+            assert!(points.contains(&Pos::new(p.x, -p.y)));
+        }
+    }
+
+    #[test]
+    fn octant_boundaries() {
+        let ese: Vec<_> = octant(Pos::new(0, 0), 5, Octant::Ese).collect();
+        assert_eq!(
+            ese,
+            &[
+                Pos::new(5, 0),
+                Pos::new(5, 1),
+                Pos::new(5, 2),
+                Pos::new(4, 3),
+            ]
+        );
+        // The axis position (0, 5) belongs to the next octant.
+        let sse: Vec<_> = octant(Pos::new(0, 0), 5, Octant::Sse).collect();
+        assert_eq!(sse, &[Pos::new(3, 4), Pos::new(2, 5), Pos::new(1, 5)]);
+    }
+
+    #[test]
+    fn octant_with_diagonal() {
+        // At radius 3, (2, 2) lies on the diagonal and belongs to the second octant.
+        let ese: Vec<_> = octant(Pos::new(0, 0), 3, Octant::Ese).collect();
+        assert_eq!(ese, &[Pos::new(3, 0), Pos::new(3, 1)]);
+        let sse: Vec<_> = octant(Pos::new(0, 0), 3, Octant::Sse).collect();
+        assert_eq!(sse, &[Pos::new(2, 2), Pos::new(1, 3)]);
+    }
+
+    #[test]
+    fn octant_len() {
+        let iter = octant(Pos::new(0, 0), 5, Octant::Nnw);
+        assert_eq!(iter.len(), 3);
+        assert_eq!(iter.count(), 3);
+    }
+
+    #[test]
+    fn arc_single_octant() {
+        let arc: Vec<_> = arc(Pos::new(0, 0), 5, Octant::Wnw, Octant::Wnw).collect();
+        let octant: Vec<_> = octant(Pos::new(0, 0), 5, Octant::Wnw).collect();
+        assert_eq!(arc, octant);
+    }
+
+    #[test]
+    fn arc_wraps_around() {
+        let points: Vec<_> = arc(Pos::new(0, 0), 1, Octant::Ene, Octant::Ese).collect();
+        assert_eq!(points, &[Pos::new(1, 0)]);
+
+        let points: Vec<_> = arc(Pos::new(0, 0), 1, Octant::Nnw, Octant::Sse).collect();
+        assert_eq!(points, &[Pos::new(0, -1), Pos::new(1, 0)]);
+    }
+
+    #[test]
+    fn octant_next_cycles() {
+        let mut octant = Octant::Ese;
+        for expected in Octant::ALL.iter().cycle().skip(1).take(8) {
+            octant = octant.next();
+            assert_eq!(octant, *expected);
+        }
+    }
+
+    #[test]
+    fn narrow_integer_radius() {
+        let points: Vec<_> = circle(Pos::new(0i16, 0), 200).collect();
+        let wide: Vec<_> = circle(Pos::new(0i32, 0), 200).collect();
+        assert_eq!(points.len(), wide.len());
+        assert!(
+            points
+                .iter()
+                .zip(&wide)
+                .all(|(p, w)| i32::from(p.x) == w.x && i32::from(p.y) == w.y)
+        );
+        assert_eq!(filled_spans(Pos::new(0i8, 0), 100).count(), 201);
+    }
+
+    #[test]
+    fn filled_spans_cover_outline() {
+        for radius in 0..20 {
+            let spans: Vec<_> = filled_spans(Pos::new(2, -3), radius).collect();
+            let outline: Vec<_> = circle(Pos::new(2, -3), radius).collect();
+            assert_eq!(spans.len(), usize::try_from(radius * 2 + 1).unwrap());
+            for p in &outline {
+                let (_, span) = spans.iter().find(|(y, _)| *y == p.y).unwrap();
+                assert!(span.contains(p.x), "radius {radius}, {p}");
+            }
+            for (y, span) in &spans {
+                assert!(
+                    outline.contains(&Pos::new(span.start, *y)),
+                    "radius {radius}"
+                );
+                assert!(
+                    outline.contains(&Pos::new(span.end - 1, *y)),
+                    "radius {radius}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn filled_spans_radius_two() {
+        let spans: Vec<_> = filled_spans(Pos::new(0, 0), 2).collect();
+        assert_eq!(
+            spans,
+            &[
+                (-2, Span::new(-1, 2)),
+                (-1, Span::new(-2, 3)),
+                (0, Span::new(-2, 3)),
+                (1, Span::new(-2, 3)),
+                (2, Span::new(-1, 2)),
+            ]
+        );
+    }
+}
@@ -0,0 +1,253 @@
+//! Collision tests for rectangles moving along integer velocities.
+
+use crate::{
+    Direction, Pos, Rect, Span,
+    int::SignedInt,
+    internal,
+    layout::{RowMajor, Traversal},
+};
+
+/// Finds the first collision of `moving` travelling by `velocity` against `obstacles`.
+///
+/// The movement is split into `max(|velocity.x|, |velocity.y|)` steps, where after step `k` of
+/// `n` the rectangle is offset by `velocity * k / n` (rounded towards zero), so the last step
+/// lands exactly on `moving + velocity`. Obstacles that already overlap `moving` are ignored.
+///
+/// Returns `None` if the full movement is free. Otherwise, returns:
+///
+/// - the number of steps taken before the collision;
+/// - the rectangle at its last free position;
+/// - the direction of movement that was blocked, e.g. [`Direction::Right`] when hitting the left
+///   side of an obstacle. If only a corner is hit, the axis with the larger velocity is blocked.
+///
+/// ## Examples
+///
+/// ```rust
+/// use ixy::{Direction, Pos, Rect, ops::collide};
+///
+/// let player = Rect::from_ltwh(0, 0, 2, 2);
+/// let wall = Rect::from_ltwh(5, -10, 1, 20);
+///
+/// let hit = collide::sweep(player, Pos::new(6, 2), [wall]);
+/// assert_eq!(hit, Some((3, Rect::from_ltwh(3, 1, 2, 2), Direction::Right)));
+///
+/// assert_eq!(collide::sweep(player, Pos::new(0, 6), [wall]), None);
+/// ```
+pub fn sweep<T: SignedInt>(
+    moving: Rect<T>,
+    velocity: Pos<T>,
+    obstacles: impl IntoIterator<Item = Rect<T>>,
+) -> Option<(usize, Rect<T>, Direction)> {
+    let steps = step_count(velocity);
+    let offset = |k| step_offset(velocity, k, steps);
+
+    let mut first: Option<(usize, Rect<T>)> = None;
+    for obstacle in obstacles {
+        if moving.intersects(obstacle) {
+            continue;
+        }
+        let limit = first.map_or(steps, |(k, _)| k);
+        if let Some(k) = (1..=limit).find(|&k| (moving + offset(k)).intersects(obstacle)) {
+            if first.is_none_or(|(j, _)| k < j) {
+                first = Some((k, obstacle));
+            }
+        }
+    }
+
+    let (k, obstacle) = first?;
+    let from = offset(k - 1);
+    let to = offset(k);
+    let x_blocked = (moving + Pos::new(to.x, from.y)).intersects(obstacle);
+    let y_blocked = (moving + Pos::new(from.x, to.y)).intersects(obstacle);
+    let blocked_x = if x_blocked == y_blocked {
+        velocity.x.abs() >= velocity.y.abs()
+    } else {
+        x_blocked
+    };
+    let direction = match (blocked_x, velocity.x >= T::ZERO, velocity.y >= T::ZERO) {
+        (true, true, _) => Direction::Right,
+        (true, false, _) => Direction::Left,
+        (false, _, true) => Direction::Down,
+        (false, _, false) => Direction::Up,
+    };
+    Some((k - 1, moving + from, direction))
+}
+
+/// Returns the cells that `rect` newly overlaps while moving by `delta`, in sweep order.
+///
+/// The movement is split into steps in the same way as [`sweep`]. Cells are yielded in the order
+/// they are first entered, and cells within the same step in row-major order. Each cell is yielded
+/// at most once, and cells already overlapped by `rect` are never yielded.
+///
+/// Positions are treated as unit cells; use [`ops::cell`](crate::ops::cell) to map a world
+/// rectangle to tile coordinates first.
+///
+/// ## Examples
+///
+/// ```rust
+/// use ixy::{Pos, Rect, ops::collide};
+///
+/// let rect = Rect::from_ltwh(0, 0, 2, 2);
+/// let cells: Vec<_> = collide::cells_entered(rect, Pos::new(2, 0)).collect();
+/// assert_eq!(
+///     cells,
+///     &[Pos::new(2, 0), Pos::new(2, 1), Pos::new(3, 0), Pos::new(3, 1)]
+/// );
+/// ```
+pub fn cells_entered<T: SignedInt>(rect: Rect<T>, delta: Pos<T>) -> impl Iterator<Item = Pos<T>> {
+    let steps = if rect.is_empty() {
+        0
+    } else {
+        step_count(delta)
+    };
+    (1..=steps).flat_map(move |k| {
+        let prev = rect + step_offset(delta, k - 1, steps);
+        let next = rect + step_offset(delta, k, steps);
+        let (new_x, kept_x) = entered(prev.x_span(), next.x_span());
+        let (new_y, _) = entered(prev.y_span(), next.y_span());
+        let column = Rect::from_spans(new_x, next.y_span());
+        let row = Rect::from_spans(kept_x, new_y);
+        RowMajor::iter_pos(column).chain(RowMajor::iter_pos(row))
+    })
+}
+
+/// Returns the number of steps to move by `velocity` one cell at a time along the longer axis.
+fn step_count<T: SignedInt>(velocity: Pos<T>) -> usize {
+    velocity.x.abs().max(velocity.y.abs()).to_usize()
+}
+
+/// Returns the offset after step `k` of `n` when moving by `velocity`, rounded towards zero.
+fn step_offset<T: SignedInt>(velocity: Pos<T>, k: usize, n: usize) -> Pos<T> {
+    Pos::new(
+        internal::lerp_towards_a(T::ZERO, velocity.x, k, n),
+        internal::lerp_towards_a(T::ZERO, velocity.y, k, n),
+    )
+}
+
+/// Splits `next` into the part not covered by `prev` and the part that is.
+///
+/// Both spans must have the same non-zero length and start at most one apart.
+fn entered<T: SignedInt>(prev: Span<T>, next: Span<T>) -> (Span<T>, Span<T>) {
+    let kept = next.intersect(prev);
+    let new = if next.start > prev.start {
+        Span::new(kept.end, next.end)
+    } else {
+        Span::new(next.start, kept.start)
+    };
+    (new, kept)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn no_movement() {
+        let rect = Rect::from_ltwh(0, 0, 1, 1);
+        let obstacle = Rect::from_ltwh(1, 0, 1, 1);
+        assert_eq!(sweep(rect, Pos::ORIGIN, [obstacle]), None);
+    }
+
+    #[test]
+    fn ignores_initial_overlap() {
+        let rect = Rect::from_ltwh(0, 0, 2, 2);
+        let obstacle = Rect::from_ltwh(1, 1, 4, 4);
+        assert_eq!(sweep(rect, Pos::new(3, 0), [obstacle]), None);
+    }
+
+    #[test]
+    fn nearest_obstacle_wins() {
+        let rect = Rect::from_ltwh(0, 0, 1, 1);
+        let far = Rect::from_ltwh(0, -8, 1, 1);
+        let near = Rect::from_ltwh(0, -4, 1, 1);
+        assert_eq!(
+            sweep(rect, Pos::new(0, -10), [far, near]),
+            Some((3, Rect::from_ltwh(0, -3, 1, 1), Direction::Up))
+        );
+    }
+
+    #[test]
+    fn landing_on_floor() {
+        let rect = Rect::from_ltwh(0, 0, 2, 2);
+        let floor = Rect::from_ltwh(-10, 5, 20, 1);
+        assert_eq!(
+            sweep(rect, Pos::new(-2, 6), [floor]),
+            Some((3, Rect::from_ltwh(-1, 3, 2, 2), Direction::Down))
+        );
+    }
+
+    #[test]
+    fn corner_hit_blocks_faster_axis() {
+        let rect = Rect::from_ltwh(0, 0, 1, 1);
+        let block = Rect::from_ltwh(2, 2, 1, 1);
+        assert_eq!(
+            sweep(rect, Pos::new(3, 3), [block]),
+            Some((1, Rect::from_ltwh(1, 1, 1, 1), Direction::Right))
+        );
+    }
+
+    #[test]
+    fn narrow_integer_velocity() {
+        let rect = Rect::from_ltwh(0i16, 0, 1, 1);
+        let wall = Rect::from_ltwh(150i16, -10, 1, 20);
+        assert_eq!(
+            sweep(rect, Pos::new(200, 0), [wall]),
+            Some((149, Rect::from_ltwh(149, 0, 1, 1), Direction::Right))
+        );
+        assert_eq!(sweep(rect, Pos::new(-200, 100), [wall]), None);
+    }
+
+    #[test]
+    fn cells_entered_diagonal() {
+        extern crate alloc;
+        use alloc::vec::Vec;
+
+        let rect = Rect::from_ltwh(0, 0, 2, 2);
+        let cells: Vec<_> = cells_entered(rect, Pos::new(-1, 1)).collect();
+        assert_eq!(cells, &[Pos::new(-1, 1), Pos::new(-1, 2), Pos::new(0, 2)]);
+    }
+
+    #[test]
+    fn cells_entered_matches_swept_area() {
+        extern crate alloc;
+        use alloc::vec::Vec;
+
+        let rect = Rect::from_ltwh(3, -2, 3, 2);
+        let delta = Pos::new(-5, 3);
+        let mut cells: Vec<_> = cells_entered(rect, delta).collect();
+        let mut expected: Vec<_> = (0..=5)
+            .flat_map(|k| RowMajor::iter_pos(rect + step_offset(delta, k, 5)))
+            .filter(|p| !rect.contains_pos(*p))
+            .collect();
+        expected.sort();
+        expected.dedup();
+        cells.sort();
+        assert_eq!(cells, expected);
+    }
+
+    #[test]
+    fn cells_entered_vertical() {
+        extern crate alloc;
+        use alloc::vec::Vec;
+
+        let rect = Rect::from_ltwh(0, 0, 2, 2);
+        let cells: Vec<_> = cells_entered(rect, Pos::new(0, -1)).collect();
+        assert_eq!(cells, &[Pos::new(0, -1), Pos::new(1, -1)]);
+    }
+
+    #[test]
+    fn cells_entered_narrow_integer_delta() {
+        let rect = Rect::from_ltwh(0i16, 0, 1, 2);
+        assert_eq!(cells_entered(rect, Pos::new(200, 0)).count(), 400);
+        assert_eq!(
+            cells_entered(rect, Pos::new(200, 0)).last(),
+            Some(Pos::new(200, 1))
+        );
+    }
+
+    #[test]
+    fn cells_entered_empty_rect() {
+        let rect = Rect::from_ltwh(0, 0, 0, 2);
+        assert_eq!(cells_entered(rect, Pos::new(3, 3)).count(), 0);
+    }
+}
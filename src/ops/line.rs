@@ -1,8 +1,8 @@
 //! Line operations.
 
-use core::iter::FusedIterator;
+use core::iter::{FusedIterator, Peekable, Skip, StepBy};
 
-use crate::{Pos, int::Int};
+use crate::{Pos, Rect, Span, int::Int};
 
 /// Calculates positions along a line using a fast 2D vector algorithm.
 ///
@@ -79,9 +79,192 @@ where
 
 impl<T> FusedIterator for VectorIter<T> where T: Int {}
 
+/// Builder-style adapters for iterators over positions along a line.
+///
+/// Implemented for every iterator of [`Pos<T>`], including the ones returned by [`vector`].
+///
+/// ## Examples
+///
+/// ```rust
+/// use ixy::{Pos, Rect, ops::line::{self, LineExt}};
+///
+/// let iter = line::vector(Pos::new(0, 0), Pos::new(6, 0))
+///     .skip_start()
+///     .clipped(Rect::from_ltwh(0, 0, 5, 1))
+///     .stepped(2);
+/// assert_eq!(iter.collect::<Vec<_>>(), &[Pos::new(1, 0), Pos::new(3, 0)]);
+/// ```
+pub trait LineExt<T: Int>: Iterator<Item = Pos<T>> + Sized {
+    /// Excludes the start position of the line.
+    ///
+    /// Useful when chaining segments, where the end of one segment is the start of the next.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use ixy::{Pos, ops::line::{self, LineExt}};
+    ///
+    /// let mut iter = line::vector(Pos::new(0, 0), Pos::new(2, 0)).skip_start();
+    /// assert_eq!(iter.next(), Some(Pos::new(1, 0)));
+    /// assert_eq!(iter.next(), Some(Pos::new(2, 0)));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    fn skip_start(self) -> Skip<Self> {
+        self.skip(1)
+    }
+
+    /// Yields only the positions of the line that are within `bounds`.
+    ///
+    /// Every position is tested, so this also works for iterators that leave and re-enter
+    /// `bounds`, such as [`circle`](crate::ops::circle::circle) outlines.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use ixy::{Pos, Rect, ops::line::{self, LineExt}};
+    ///
+    /// let bounds = Rect::from_ltwh(1, 0, 2, 1);
+    /// let mut iter = line::vector(Pos::new(0, 0), Pos::new(5, 0)).clipped(bounds);
+    /// assert_eq!(iter.next(), Some(Pos::new(1, 0)));
+    /// assert_eq!(iter.next(), Some(Pos::new(2, 0)));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    fn clipped(self, bounds: Rect<T>) -> Clipped<Self, T> {
+        Clipped { iter: self, bounds }
+    }
+
+    /// Yields every `n`th position of the line, starting with the first.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `n` is `0`.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use ixy::{Pos, ops::line::{self, LineExt}};
+    ///
+    /// let mut iter = line::vector(Pos::new(0, 0), Pos::new(4, 0)).stepped(2);
+    /// assert_eq!(iter.next(), Some(Pos::new(0, 0)));
+    /// assert_eq!(iter.next(), Some(Pos::new(2, 0)));
+    /// assert_eq!(iter.next(), Some(Pos::new(4, 0)));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    fn stepped(self, n: usize) -> StepBy<Self> {
+        assert!(n > 0, "step must be greater than 0");
+        self.step_by(n)
+    }
+
+    /// Merges horizontally adjacent positions into spans of `(y, x_start..x_end)`.
+    ///
+    /// Consecutive positions on the same row, each one step left or right of the previous, are
+    /// yielded as a single half-open [`Span`], so a shallow line can be drawn with one slice fill
+    /// per row instead of one write per position.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use ixy::{Pos, Span, ops::line::{self, LineExt}};
+    ///
+    /// let mut iter = line::vector(Pos::new(3, 1), Pos::new(0, 1)).spans();
+    /// assert_eq!(iter.next(), Some((1, Span::new(0, 4))));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    fn spans(self) -> Spans<Self, T> {
+        Spans {
+            iter: self.peekable(),
+        }
+    }
+}
+
+impl<T: Int, I: Iterator<Item = Pos<T>>> LineExt<T> for I {}
+
+/// An iterator that yields the positions of a line within a rectangle.
+///
+/// Created by [`LineExt::clipped`].
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Clipped<I, T> {
+    iter: I,
+    bounds: Rect<T>,
+}
+
+impl<I, T> Iterator for Clipped<I, T>
+where
+    I: Iterator<Item = Pos<T>>,
+    T: Int,
+{
+    type Item = Pos<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let bounds = self.bounds;
+        self.iter.find(|pos| bounds.contains_pos(*pos))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.iter.size_hint().1)
+    }
+}
+
+impl<I, T> FusedIterator for Clipped<I, T>
+where
+    I: FusedIterator<Item = Pos<T>>,
+    T: Int,
+{
+}
+
+/// An iterator that merges the positions of a line into horizontal spans.
+///
+/// Created by [`LineExt::spans`].
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Spans<I, T>
+where
+    I: Iterator<Item = Pos<T>>,
+{
+    iter: Peekable<I>,
+}
+
+impl<I, T> Iterator for Spans<I, T>
+where
+    I: Iterator<Item = Pos<T>>,
+    T: Int,
+{
+    type Item = (T, Span<T>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let first = self.iter.next()?;
+        let (mut min, mut max, mut last) = (first.x, first.x, first.x);
+        while let Some(pos) = self
+            .iter
+            .next_if(|pos| pos.y == first.y && (pos.x == last + T::ONE || pos.x + T::ONE == last))
+        {
+            last = pos.x;
+            min = min.min(pos.x);
+            max = max.max(pos.x);
+        }
+        Some((first.y, Span::new(min, max + T::ONE)))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.iter.size_hint();
+        (lower.min(1), upper)
+    }
+}
+
+impl<I, T> FusedIterator for Spans<I, T>
+where
+    I: FusedIterator<Item = Pos<T>>,
+    T: Int,
+{
+}
+
 #[cfg(test)]
 mod tests {
+    extern crate alloc;
+
     use super::*;
+    use alloc::vec::Vec;
 
     #[test]
     fn vector_iter_blank() {
@@ -170,4 +353,103 @@ mod tests {
         assert_eq!(iter.size_hint(), (0, Some(0)));
         assert_eq!(iter.next(), None);
     }
+
+    #[test]
+    fn skip_start_excludes_first() {
+        let mut iter = vector(Pos::new(0, 0), Pos::new(0, 2)).skip_start();
+        assert_eq!(iter.next(), Some(Pos::new(0, 1)));
+        assert_eq!(iter.next(), Some(Pos::new(0, 2)));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn skip_start_single_point() {
+        let mut iter = vector(Pos::new(1, 1), Pos::new(1, 1)).skip_start();
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn clipped_enters_and_leaves() {
+        let bounds = Rect::from_ltwh(1, 1, 2, 2);
+        let mut iter = vector(Pos::new(0, 0), Pos::new(4, 4)).clipped(bounds);
+        assert_eq!(iter.next(), Some(Pos::new(1, 1)));
+        assert_eq!(iter.next(), Some(Pos::new(2, 2)));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn clipped_reenters() {
+        extern crate alloc;
+        use alloc::vec::Vec;
+
+        let bounds = Rect::from_ltwh(-1, -3, 3, 7);
+        let clipped: Vec<_> = crate::ops::circle::circle(Pos::new(0, 0), 3)
+            .clipped(bounds)
+            .collect();
+        let filtered: Vec<_> = crate::ops::circle::circle(Pos::new(0, 0), 3)
+            .filter(|p| bounds.contains_pos(*p))
+            .collect();
+        assert_eq!(clipped, filtered);
+        assert!(clipped.contains(&Pos::new(0, -3)));
+        assert!(clipped.contains(&Pos::new(0, 3)));
+    }
+
+    #[test]
+    fn clipped_outside() {
+        let bounds = Rect::from_ltwh(10, 10, 2, 2);
+        let mut iter = vector(Pos::new(0, 0), Pos::new(4, 4)).clipped(bounds);
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn stepped_every_third() {
+        let mut iter = vector(Pos::new(0, 0), Pos::new(5, 0)).stepped(3);
+        assert_eq!(iter.next(), Some(Pos::new(0, 0)));
+        assert_eq!(iter.next(), Some(Pos::new(3, 0)));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "step must be greater than 0")]
+    fn stepped_zero_panics() {
+        let _ = vector(Pos::new(0, 0), Pos::new(5, 0)).stepped(0);
+    }
+
+    #[test]
+    fn spans_merges_rows() {
+        let spans: Vec<_> = [
+            Pos::new(0, 0),
+            Pos::new(1, 0),
+            Pos::new(2, 1),
+            Pos::new(3, 1),
+            Pos::new(4, 1),
+        ]
+        .into_iter()
+        .spans()
+        .collect();
+        assert_eq!(spans, &[(0, Span::new(0, 2)), (1, Span::new(2, 5))]);
+    }
+
+    #[test]
+    fn spans_splits_non_adjacent() {
+        let spans: Vec<_> = [
+            Pos::new(0, 0),
+            Pos::new(2, 0),
+            Pos::new(2, 1),
+            Pos::new(2, 2),
+        ]
+        .into_iter()
+        .spans()
+        .collect();
+        assert_eq!(
+            spans,
+            &[
+                (0, Span::new(0, 1)),
+                (0, Span::new(2, 3)),
+                (1, Span::new(2, 3)),
+                (2, Span::new(2, 3)),
+            ]
+        );
+    }
 }
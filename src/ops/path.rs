@@ -0,0 +1,104 @@
+//! Operations on paths, i.e. sequences of waypoints.
+
+use crate::{Pos, int::Int};
+
+/// Removes redundant waypoints from a path in place, using string pulling.
+///
+/// Starting at the first waypoint, each waypoint is kept only if the path could not go directly
+/// from the last kept waypoint to the one after it, according to `has_los` (line of sight). The
+/// first and last waypoints are always kept.
+///
+/// The kept waypoints are moved to the front of `path`, in order, and their count is returned.
+/// This turns the zig-zag paths produced by grid searches into straighter, any-angle paths.
+///
+/// ## Examples
+///
+/// ```rust
+/// use ixy::{Pos, ops::path};
+///
+/// // A staircase path across an open area.
+/// let mut waypoints = [
+///     Pos::new(0, 0),
+///     Pos::new(1, 0),
+///     Pos::new(1, 1),
+///     Pos::new(2, 1),
+///     Pos::new(2, 2),
+/// ];
+/// let len = path::smooth(&mut waypoints, |_, _| true);
+/// assert_eq!(&waypoints[..len], &[Pos::new(0, 0), Pos::new(2, 2)]);
+///
+/// // If only horizontal and vertical moves are visible, the corner is kept.
+/// let mut waypoints = [Pos::new(0, 0), Pos::new(0, 2), Pos::new(3, 2)];
+/// let len = path::smooth(&mut waypoints, |a, b| a.x == b.x || a.y == b.y);
+/// assert_eq!(len, 3);
+/// ```
+pub fn smooth<T: Int>(
+    path: &mut [Pos<T>],
+    mut has_los: impl FnMut(Pos<T>, Pos<T>) -> bool,
+) -> usize {
+    if path.len() <= 2 {
+        return path.len();
+    }
+    let mut anchor = path[0];
+    let mut len = 1;
+    for i in 2..path.len() {
+        if !has_los(anchor, path[i]) {
+            anchor = path[i - 1];
+            path[len] = anchor;
+            len += 1;
+        }
+    }
+    path[len] = path[path.len() - 1];
+    len + 1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn short_paths_unchanged() {
+        let mut empty: [Pos<i32>; 0] = [];
+        assert_eq!(smooth(&mut empty, |_, _| true), 0);
+
+        let mut pair = [Pos::new(0, 0), Pos::new(5, 5)];
+        assert_eq!(smooth(&mut pair, |_, _| false), 2);
+        assert_eq!(pair, [Pos::new(0, 0), Pos::new(5, 5)]);
+    }
+
+    #[test]
+    fn no_line_of_sight_keeps_everything() {
+        let original = [
+            Pos::new(0u8, 0),
+            Pos::new(1, 0),
+            Pos::new(2, 0),
+            Pos::new(3, 0),
+        ];
+        let mut path = original;
+        assert_eq!(smooth(&mut path, |_, _| false), 4);
+        assert_eq!(path, original);
+    }
+
+    #[test]
+    fn keeps_corners_without_line_of_sight() {
+        // Only straight horizontal or vertical moves are visible.
+        let mut path = [
+            Pos::new(0, 0),
+            Pos::new(1, 0),
+            Pos::new(2, 0),
+            Pos::new(2, 1),
+            Pos::new(2, 2),
+            Pos::new(3, 2),
+        ];
+        let len = smooth(&mut path, |a, b| a.x == b.x || a.y == b.y);
+        assert_eq!(
+            &path[..len],
+            &[
+                Pos::new(0, 0),
+                Pos::new(2, 0),
+                Pos::new(2, 2),
+                Pos::new(3, 2)
+            ]
+        );
+    }
+}
@@ -0,0 +1,170 @@
+//! Operations on sets of rectangles.
+
+use crate::{Rect, int::Int};
+
+/// Returns the total area covered by `rects`, counting overlapping regions once.
+///
+/// Uses a sweep-line over the distinct x-coordinates of the rectangle edges, measuring the union
+/// of the rectangles crossing each vertical slab. No allocation is performed, at the cost of
+/// `O(n³)` time for `n` rectangles, so this is intended for small sets (e.g. damage regions).
+///
+/// Empty rectangles do not contribute to the area. Returns [`None`] if the area cannot be
+/// represented by a [`u64`], like [`Rect::area_u64`].
+///
+/// ## Examples
+///
+/// ```rust
+/// use ixy::{Rect, ops::rects};
+///
+/// let a = Rect::from_ltwh(0, 0, 4, 4);
+/// let b = Rect::from_ltwh(2, 2, 4, 4);
+/// assert_eq!(rects::covered_area(&[a, b]), Some(28));
+///
+/// let huge = Rect::from_ltwh(0u64, 0, 1 << 32, 1 << 32);
+/// assert_eq!(rects::covered_area(&[huge]), None);
+/// ```
+#[must_use]
+pub fn covered_area<T: Int>(rects: &[Rect<T>]) -> Option<u64> {
+    let horizontal = |r: &Rect<T>| (!r.is_empty()).then(|| (r.left(), r.right()));
+    let mut area = 0u64;
+    for_each_slab(rects, horizontal, |left, right| {
+        let vertical = |r: &Rect<T>| {
+            let crosses = !r.is_empty() && r.left() <= left && r.right() >= right;
+            crosses.then(|| (r.top(), r.bottom()))
+        };
+        let mut height = 0u64;
+        for_each_slab(rects, vertical, |top, bottom| {
+            height = height.checked_add(span(top, bottom)?)?;
+            Some(())
+        })?;
+        area = area.checked_add(span(left, right)?.checked_mul(height)?)?;
+        Some(())
+    })?;
+    Some(area)
+}
+
+/// Calls `f` with each slab `[start, end)` between consecutive distinct edges that is covered by
+/// at least one of the `(start, end)` extents returned by `extent`.
+///
+/// Rectangles for which `extent` returns [`None`] are ignored. Stops and returns [`None`] as soon
+/// as `f` does.
+fn for_each_slab<T: Int>(
+    rects: &[Rect<T>],
+    extent: impl Fn(&Rect<T>) -> Option<(T, T)>,
+    mut f: impl FnMut(T, T) -> Option<()>,
+) -> Option<()> {
+    let next_edge = |after: Option<T>| {
+        rects
+            .iter()
+            .filter_map(&extent)
+            .flat_map(<[T; 2]>::from)
+            .filter(|&edge| after.is_none_or(|after| edge > after))
+            .min()
+    };
+    let mut start = next_edge(None);
+    while let Some(slab_start) = start {
+        let Some(slab_end) = next_edge(Some(slab_start)) else {
+            break;
+        };
+        let covered = rects
+            .iter()
+            .filter_map(&extent)
+            .any(|(start, end)| start <= slab_start && end >= slab_end);
+        if covered {
+            f(slab_start, slab_end)?;
+        }
+        start = Some(slab_end);
+    }
+    Some(())
+}
+
+/// Returns `end - start` as a [`u64`], where `start <= end` lie within a single rectangle.
+fn span<T: Int>(start: T, end: T) -> Option<u64> {
+    (end - start).checked_to_u64()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty_set() {
+        assert_eq!(covered_area::<i32>(&[]), Some(0));
+    }
+
+    #[test]
+    fn single_rect() {
+        assert_eq!(covered_area(&[Rect::from_ltwh(-3, -3, 5, 7)]), Some(35));
+    }
+
+    #[test]
+    fn disjoint_rects() {
+        let rects = [Rect::from_ltwh(0, 0, 2, 2), Rect::from_ltwh(10, 10, 3, 3)];
+        assert_eq!(covered_area(&rects), Some(13));
+    }
+
+    #[test]
+    fn contained_rect_counted_once() {
+        let rects = [Rect::from_ltwh(0, 0, 10, 10), Rect::from_ltwh(2, 2, 3, 3)];
+        assert_eq!(covered_area(&rects), Some(100));
+    }
+
+    #[test]
+    fn duplicate_rects_counted_once() {
+        let rect = Rect::from_ltwh(1, 1, 3, 3);
+        assert_eq!(covered_area(&[rect, rect, rect]), Some(9));
+    }
+
+    #[test]
+    fn touching_rects() {
+        let rects = [Rect::from_ltwh(0, 0, 2, 2), Rect::from_ltwh(2, 0, 2, 2)];
+        assert_eq!(covered_area(&rects), Some(8));
+    }
+
+    #[test]
+    fn empty_rects_ignored() {
+        let rects = [
+            Rect::from_ltwh(0, 0, 0, 100),
+            Rect::from_ltwh(0, 0, 100, 0),
+            Rect::from_ltwh(5, 5, 1, 1),
+        ];
+        assert_eq!(covered_area(&rects), Some(1));
+    }
+
+    #[test]
+    fn cross_shape() {
+        let rects = [Rect::from_ltwh(0, 2, 6, 2), Rect::from_ltwh(2, 0, 2, 6)];
+        assert_eq!(covered_area(&rects), Some(20));
+    }
+
+    #[test]
+    fn matches_pos_count() {
+        let rects = [
+            Rect::from_ltwh(0u8, 0, 5, 3),
+            Rect::from_ltwh(3, 1, 4, 6),
+            Rect::from_ltwh(1, 4, 2, 2),
+            Rect::from_ltwh(6, 0, 1, 1),
+        ];
+        let mut count = 0;
+        for y in 0..10 {
+            for x in 0..10 {
+                if rects.iter().any(|r| r.contains(x, y)) {
+                    count += 1;
+                }
+            }
+        }
+        assert_eq!(covered_area(&rects), Some(count));
+    }
+
+    #[test]
+    fn overflow_returns_none() {
+        let rects = [
+            Rect::from_ltwh(0u64, 0, 1 << 32, 1 << 31),
+            Rect::from_ltwh(0, 1 << 31, 1 << 32, 1 << 31),
+        ];
+        assert_eq!(covered_area(&rects), None);
+        assert_eq!(covered_area(&rects[..1]), Some(1 << 63));
+        let wide = Rect::from_ltrb(0u128, 0, 1 << 64, 1).unwrap();
+        assert_eq!(covered_area(&[wide]), None);
+    }
+}
@@ -0,0 +1,213 @@
+//! Rectangles with rounded corners.
+//!
+//! Each corner is a quarter of a [`circle`](crate::ops::circle) with the given radius, connected
+//! by straight edges. The radius is clamped so that opposite corners never overlap, i.e. to at
+//! most `(width - 1) / 2` and `(height - 1) / 2`; a radius of `0` gives square corners.
+
+use crate::{
+    Pos, Rect, Span,
+    int::SignedInt,
+    layout::{RowMajor, Traversal},
+    ops::circle::{self, Octant},
+};
+
+/// Calculates positions along the outline of a rectangle with rounded corners.
+///
+/// Positions are yielded clockwise, starting at the left end of the top edge, and each position is
+/// yielded exactly once. A rectangle that is only one cell wide or tall yields all of its
+/// positions in row-major order.
+///
+/// ## Examples
+///
+/// ```rust
+/// use ixy::{Pos, Rect, ops::rounded_rect};
+///
+/// let rect = Rect::from_ltwh(0, 0, 5, 4);
+/// let points: Vec<_> = rounded_rect::outline(rect, 1).collect();
+/// assert_eq!(points.len(), 10);
+/// assert!(!points.contains(&Pos::new(0, 0)));
+/// assert!(points.contains(&Pos::new(1, 0)));
+/// assert!(points.contains(&Pos::new(0, 1)));
+/// ```
+pub fn outline<T: SignedInt>(rect: Rect<T>, radius: T) -> impl Iterator<Item = Pos<T>> {
+    let degenerate = rect.width() <= T::ONE || rect.height() <= T::ONE;
+    let r = clamp_radius(rect, radius);
+    let (l, t) = (rect.left(), rect.top());
+    let (right, bottom) = (rect.right() - T::ONE, rect.bottom() - T::ONE);
+    let two_r = (r + r).to_usize();
+    let (edge_w, edge_h) = if degenerate {
+        (0, 0)
+    } else {
+        (
+            rect.width_usize() - 1 - two_r,
+            rect.height_usize() - 1 - two_r,
+        )
+    };
+    let corner = move |center: Pos<T>, start, end| {
+        circle::arc(center, r, start, end).filter(move |_| !degenerate && r > T::ZERO)
+    };
+    let all = if degenerate { rect } else { Rect::EMPTY };
+    RowMajor::iter_pos(all)
+        .chain(run(Pos::new(l + r, t), Pos::X, edge_w))
+        .chain(corner(Pos::new(right - r, t + r), Octant::Nne, Octant::Ene))
+        .chain(run(Pos::new(right, t + r), Pos::Y, edge_h))
+        .chain(corner(
+            Pos::new(right - r, bottom - r),
+            Octant::Ese,
+            Octant::Sse,
+        ))
+        .chain(run(Pos::new(right - r, bottom), Pos::NEG_X, edge_w))
+        .chain(corner(
+            Pos::new(l + r, bottom - r),
+            Octant::Ssw,
+            Octant::Wsw,
+        ))
+        .chain(run(Pos::new(l, bottom - r), Pos::NEG_Y, edge_h))
+        .chain(corner(Pos::new(l + r, t + r), Octant::Wnw, Octant::Nnw))
+}
+
+/// Calculates the rows of a filled rectangle with rounded corners, as spans of
+/// `(y, x_start..x_end)`.
+///
+/// Rows are yielded from top to bottom, and each span covers exactly the [`outline`] positions on
+/// that row and everything between them. An empty rectangle yields no spans.
+///
+/// ## Examples
+///
+/// ```rust
+/// use ixy::{Rect, Span, ops::rounded_rect};
+///
+/// let rect = Rect::from_ltwh(0, 0, 5, 4);
+/// let spans: Vec<_> = rounded_rect::filled_spans(rect, 1).collect();
+/// assert_eq!(
+///     spans,
+///     &[
+///         (0, Span::new(1, 4)),
+///         (1, Span::new(0, 5)),
+///         (2, Span::new(0, 5)),
+///         (3, Span::new(1, 4)),
+///     ]
+/// );
+/// ```
+pub fn filled_spans<T: SignedInt>(rect: Rect<T>, radius: T) -> impl Iterator<Item = (T, Span<T>)> {
+    let r = clamp_radius(rect, radius);
+    let rows = r.to_usize();
+    let (l, t) = (rect.left(), rect.top());
+    let (right, bottom) = (rect.right() - T::ONE, rect.bottom() - T::ONE);
+    let join = |((y, a), (_, b)): ((T, Span<T>), (T, Span<T>))| (y, Span::new(a.start, b.end));
+
+    let top = circle::filled_spans(Pos::new(l + r, t + r), r)
+        .zip(circle::filled_spans(Pos::new(right - r, t + r), r))
+        .take(rows)
+        .map(join);
+    let middle =
+        Rect::from_ltrb_unchecked(l, t + r, rect.right(), (bottom - r + T::ONE).max(t + r));
+    let bottom = circle::filled_spans(Pos::new(l + r, bottom - r), r)
+        .zip(circle::filled_spans(Pos::new(right - r, bottom - r), r))
+        .skip(rows + 1)
+        .map(join);
+    top.chain(middle.row_spans()).chain(bottom)
+}
+
+/// Calculates positions inside a rectangle with rounded corners, including the outline.
+///
+/// Positions are yielded in row-major order.
+///
+/// ## Examples
+///
+/// ```rust
+/// use ixy::{Pos, Rect, ops::rounded_rect};
+///
+/// let rect = Rect::from_ltwh(0, 0, 5, 4);
+/// let points: Vec<_> = rounded_rect::filled(rect, 1).collect();
+/// assert_eq!(points.len(), 16);
+/// assert!(!points.contains(&Pos::new(4, 3)));
+/// ```
+pub fn filled<T: SignedInt>(rect: Rect<T>, radius: T) -> impl Iterator<Item = Pos<T>> {
+    filled_spans(rect, radius)
+        .flat_map(|(y, span)| RowMajor::iter_pos(Rect::from_spans(span, Span::new(y, y + T::ONE))))
+}
+
+/// Returns the radius clamped to fit inside the rectangle.
+fn clamp_radius<T: SignedInt>(rect: Rect<T>, radius: T) -> T {
+    if rect.is_empty() {
+        return T::ZERO;
+    }
+    let two = T::ONE + T::ONE;
+    let max = ((rect.width() - T::ONE) / two).min((rect.height() - T::ONE) / two);
+    radius.clamp(T::ZERO, max)
+}
+
+/// Returns `n` positions starting at `start`, each `step` from the previous.
+fn run<T: SignedInt>(start: Pos<T>, step: Pos<T>, n: usize) -> impl Iterator<Item = Pos<T>> {
+    (0..n).map(move |i| start + step * T::from_usize(i))
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+
+    use super::*;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn square_corners_match_border() {
+        let rect = Rect::from_ltwh(-2, 3, 4, 3);
+        let mut points: Vec<_> = outline(rect, 0).collect();
+        points.sort();
+        let mut border: Vec<_> = RowMajor::iter_pos(rect)
+            .filter(|p| !rect.deflate(1).contains_pos(*p))
+            .collect();
+        border.sort();
+        assert_eq!(points, border);
+    }
+
+    #[test]
+    fn outline_is_connected_without_duplicates() {
+        for (w, h, radius) in [
+            (5, 4, 1i32),
+            (9, 9, 4),
+            (12, 7, 2),
+            (20, 11, 100),
+            (2, 2, 1),
+        ] {
+            let rect = Rect::from_ltwh(1, -1, w, h);
+            let points: Vec<_> = outline(rect, radius).collect();
+            for (i, p) in points.iter().enumerate() {
+                let next = points[(i + 1) % points.len()];
+                let d = next - *p;
+                assert!(
+                    d.x.abs() <= 1 && d.y.abs() <= 1,
+                    "{w}x{h} r{radius}: {p} -> {next}"
+                );
+                assert!(rect.contains_pos(*p));
+            }
+            let mut sorted = points.clone();
+            sorted.sort();
+            sorted.dedup();
+            assert_eq!(sorted.len(), points.len(), "{w}x{h} r{radius}");
+        }
+    }
+
+    #[test]
+    fn filled_spans_match_outline() {
+        for (w, h, radius) in [(5, 4, 1), (9, 9, 4), (12, 7, 2), (1, 5, 3), (6, 1, 2)] {
+            let rect = Rect::from_ltwh(4, 2, w, h);
+            let spans: Vec<_> = filled_spans(rect, radius).collect();
+            let points: Vec<_> = outline(rect, radius).collect();
+            assert_eq!(spans.len(), rect.height_usize(), "{w}x{h} r{radius}");
+            for (y, span) in spans {
+                let row = points.iter().filter(|p| p.y == y).map(|p| p.x);
+                assert_eq!(row.clone().min(), Some(span.start), "{w}x{h} r{radius}");
+                assert_eq!(row.max(), Some(span.end - 1), "{w}x{h} r{radius}");
+            }
+        }
+    }
+
+    #[test]
+    fn empty_rect() {
+        let rect = Rect::from_ltwh(0, 0, 0, 5);
+        assert_eq!(outline(rect, 2).count(), 0);
+        assert_eq!(filled(rect, 2).count(), 0);
+    }
+}
@@ -0,0 +1,176 @@
+//! Axis-aligned routes between positions.
+
+use core::iter::FusedIterator;
+
+use crate::{Pos, int::Int};
+
+/// Which axis an [`l_shaped`] route travels along first.
+///
+/// ## Examples
+///
+/// ```rust
+/// use ixy::{Pos, ops::route::{self, Bend}};
+///
+/// let a = Pos::new(0, 0);
+/// let b = Pos::new(2, 2);
+/// assert!(route::l_shaped(a, b, Bend::XFirst).any(|p| p == Pos::new(2, 0)));
+/// assert!(route::l_shaped(a, b, Bend::YFirst).any(|p| p == Pos::new(0, 2)));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Bend {
+    /// Travel horizontally first, turning at `(b.x, a.y)`.
+    XFirst,
+
+    /// Travel vertically first, turning at `(a.x, b.y)`.
+    YFirst,
+}
+
+/// Returns the two-segment, axis-aligned path from `a` to `b`.
+///
+/// The resulting iterator is _inclusive_ of both `a` and `b`, visits the corner exactly once, and
+/// each position is adjacent (horizontally or vertically) to the previous one. If `a` and `b`
+/// share a row or column, the path is a single straight segment and `bend` has no effect.
+///
+/// ## Examples
+///
+/// ```rust
+/// use ixy::{Pos, ops::route::{self, Bend}};
+///
+/// let path: Vec<_> = route::l_shaped(Pos::new(0, 0), Pos::new(2, 1), Bend::XFirst).collect();
+/// assert_eq!(
+///     path,
+///     &[Pos::new(0, 0), Pos::new(1, 0), Pos::new(2, 0), Pos::new(2, 1)],
+/// );
+/// ```
+pub const fn l_shaped<T: Int>(a: Pos<T>, b: Pos<T>, bend: Bend) -> LShaped<T> {
+    LShaped {
+        next: Some(a),
+        end: b,
+        bend,
+    }
+}
+
+/// An iterator over an L-shaped route.
+///
+/// Created by [`l_shaped`].
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct LShaped<T> {
+    next: Option<Pos<T>>,
+    end: Pos<T>,
+    bend: Bend,
+}
+
+impl<T: Int> Iterator for LShaped<T> {
+    type Item = Pos<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = self.next?;
+        self.next = if current == self.end {
+            None
+        } else {
+            let mut pos = current;
+            let step_x = match self.bend {
+                Bend::XFirst => pos.x != self.end.x,
+                Bend::YFirst => pos.y == self.end.y,
+            };
+            if step_x {
+                pos.x = step_toward(pos.x, self.end.x);
+            } else {
+                pos.y = step_toward(pos.y, self.end.y);
+            }
+            Some(pos)
+        };
+        Some(current)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+}
+
+impl<T: Int> ExactSizeIterator for LShaped<T> {
+    fn len(&self) -> usize {
+        self.next.map_or(0, |pos| {
+            abs_diff(pos.x, self.end.x) + abs_diff(pos.y, self.end.y) + 1
+        })
+    }
+}
+
+impl<T: Int> FusedIterator for LShaped<T> {}
+
+/// Moves `from` one unit towards `to`.
+fn step_toward<T: Int>(from: T, to: T) -> T {
+    if from < to {
+        from + T::ONE
+    } else {
+        from - T::ONE
+    }
+}
+
+/// Returns the distance between `a` and `b`, without overflowing for unsigned types.
+fn abs_diff<T: Int>(a: T, b: T) -> usize {
+    if a < b {
+        (b - a).to_usize()
+    } else {
+        (a - b).to_usize()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+
+    use super::*;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn same_position() {
+        let path: Vec<_> = l_shaped(Pos::new(3, 3), Pos::new(3, 3), Bend::XFirst).collect();
+        assert_eq!(path, &[Pos::new(3, 3)]);
+    }
+
+    #[test]
+    fn y_first_negative_direction() {
+        let path: Vec<_> = l_shaped(Pos::new(1, 1), Pos::new(0, -1), Bend::YFirst).collect();
+        assert_eq!(
+            path,
+            &[
+                Pos::new(1, 1),
+                Pos::new(1, 0),
+                Pos::new(1, -1),
+                Pos::new(0, -1)
+            ]
+        );
+    }
+
+    #[test]
+    fn straight_ignores_bend() {
+        let a = Pos::new(0u8, 2);
+        let b = Pos::new(0u8, 0);
+        let x_first: Vec<_> = l_shaped(a, b, Bend::XFirst).collect();
+        let y_first: Vec<_> = l_shaped(a, b, Bend::YFirst).collect();
+        assert_eq!(x_first, y_first);
+        assert_eq!(x_first, &[Pos::new(0, 2), Pos::new(0, 1), Pos::new(0, 0)]);
+    }
+
+    #[test]
+    fn len_matches_manhattan_distance() {
+        let mut iter = l_shaped(Pos::new(-2, 5), Pos::new(3, 1), Bend::YFirst);
+        assert_eq!(iter.len(), 10);
+        iter.next();
+        assert_eq!(iter.len(), 9);
+        assert_eq!(iter.count(), 9);
+    }
+
+    #[test]
+    fn steps_are_adjacent() {
+        let path: Vec<_> = l_shaped(Pos::new(4, -3), Pos::new(-2, 6), Bend::XFirst).collect();
+        for pair in path.windows(2) {
+            let d = pair[1] - pair[0];
+            assert_eq!(d.x.abs() + d.y.abs(), 1);
+        }
+        assert_eq!(path.last(), Some(&Pos::new(-2, 6)));
+    }
+}
@@ -0,0 +1,164 @@
+//! Random sampling of positions.
+//!
+//! Requires the `rand` feature.
+
+use rand::{Rng, distr::uniform::SampleUniform};
+
+use crate::{Pos, Rect, int::Int};
+
+/// The number of candidates tried around each point before it is retired.
+const ATTEMPTS: usize = 30;
+
+/// Fills `out` with well-spaced random positions within `rect` (Poisson-disk sampling).
+///
+/// Every pair of positions is at least `min_dist` apart (using the Euclidean distance), and new
+/// positions are generated in the ring between `min_dist` and `2 * min_dist` around existing ones
+/// until no more fit, or `out` is full, following [Bridson's algorithm][].
+///
+/// Returns the number of positions written to the start of `out`.
+///
+/// To avoid allocating, `out` doubles as the list of active points, and neighbours are found by
+/// scanning the positions generated so far, so this takes `O(n²)` time for `n` positions.
+///
+/// [Bridson's algorithm]: https://www.cs.ubc.ca/~rbridson/docs/bridson-siggraph07-poissondisk.pdf
+///
+/// ## Panics
+///
+/// Panics if `min_dist` is `0`.
+///
+/// ## Examples
+///
+/// ```rust
+/// use ixy::{Pos, Rect, ops::sample};
+///
+/// fn scatter_trees(rng: &mut impl rand::Rng) -> usize {
+///     let forest = Rect::from_ltwh(0, 0, 64, 64);
+///     let mut trees = [Pos::ORIGIN; 256];
+///     sample::poisson_disk(forest, 5, rng, &mut trees)
+/// }
+/// ```
+pub fn poisson_disk<T, R>(rect: Rect<T>, min_dist: usize, rng: &mut R, out: &mut [Pos<T>]) -> usize
+where
+    T: Int + SampleUniform,
+    R: Rng + ?Sized,
+{
+    assert!(min_dist > 0, "minimum distance must be greater than 0");
+    if out.is_empty() {
+        return 0;
+    }
+    let Some(first) = rect.sample_pos(rng) else {
+        return 0;
+    };
+    out[0] = first;
+
+    // Positions in `out[..retired]` are done; `out[retired..len]` may still have neighbours.
+    let mut retired = 0;
+    let mut len = 1;
+    let min_sq = (min_dist as u128).pow(2);
+    let max_sq = min_sq * 4;
+    while retired < len && len < out.len() {
+        let index = rng.random_range(retired..len);
+        let center = out[index];
+        let (x_lo, x_hi) = window(center.x, rect.left(), rect.right(), min_dist * 2);
+        let (y_lo, y_hi) = window(center.y, rect.top(), rect.bottom(), min_dist * 2);
+
+        let candidate = (0..ATTEMPTS).find_map(|_| {
+            let pos = Pos::new(rng.random_range(x_lo..=x_hi), rng.random_range(y_lo..=y_hi));
+            let dist = distance_squared(center, pos);
+            let fits = (min_sq..=max_sq).contains(&dist)
+                && out[..len]
+                    .iter()
+                    .all(|&p| distance_squared(p, pos) >= min_sq);
+            fits.then_some(pos)
+        });
+        if let Some(pos) = candidate {
+            out[len] = pos;
+            len += 1;
+        } else {
+            out.swap(index, retired);
+            retired += 1;
+        }
+    }
+    len
+}
+
+/// Returns the inclusive range within `radius` of `center`, limited to `start..end`.
+fn window<T: Int>(center: T, start: T, end: T, radius: usize) -> (T, T) {
+    let lo = if (center - start).to_usize() > radius {
+        center - T::from_usize(radius)
+    } else {
+        start
+    };
+    let hi = if (end - T::ONE - center).to_usize() > radius {
+        center + T::from_usize(radius)
+    } else {
+        end - T::ONE
+    };
+    (lo, hi)
+}
+
+/// Returns the squared Euclidean distance between two positions in the same rectangle.
+fn distance_squared<T: Int>(a: Pos<T>, b: Pos<T>) -> u128 {
+    let diff = |a: T, b: T| (if a > b { a - b } else { b - a }).to_usize() as u128;
+    diff(a.x, b.x).pow(2) + diff(a.y, b.y).pow(2)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::internal::TestRng;
+
+    #[test]
+    fn positions_are_spaced_and_within_rect() {
+        let mut rng = TestRng(0x2545_F491_4F6C_DD1D);
+        let rect = Rect::from_ltwh(-20, 10, 40, 30);
+        let mut out = [Pos::ORIGIN; 200];
+        let len = poisson_disk(rect, 4, &mut rng, &mut out);
+        assert!(len > 10, "{len}");
+        let points = &out[..len];
+        for (i, &a) in points.iter().enumerate() {
+            assert!(rect.contains_pos(a), "{a}");
+            for &b in &points[i + 1..] {
+                assert!(distance_squared(a, b) >= 16, "{a} {b}");
+            }
+        }
+    }
+
+    #[test]
+    fn fills_until_out_is_full() {
+        let mut rng = TestRng(7);
+        let rect = Rect::from_ltwh(0u8, 0, 200, 200);
+        let mut out = [Pos::ORIGIN; 5];
+        assert_eq!(poisson_disk(rect, 2, &mut rng, &mut out), 5);
+    }
+
+    #[test]
+    fn single_cell_rect() {
+        let mut rng = TestRng(7);
+        let rect = Rect::from_ltwh(3, 3, 1, 1);
+        let mut out = [Pos::ORIGIN; 5];
+        assert_eq!(poisson_disk(rect, 1, &mut rng, &mut out), 1);
+        assert_eq!(out[0], Pos::new(3, 3));
+    }
+
+    #[test]
+    fn empty_rect_or_buffer() {
+        let mut rng = TestRng(7);
+        let mut out = [Pos::ORIGIN; 5];
+        assert_eq!(
+            poisson_disk(Rect::from_ltwh(0, 0, 0, 5), 1, &mut rng, &mut out),
+            0
+        );
+        assert_eq!(
+            poisson_disk(Rect::from_ltwh(0, 0, 5, 5), 1, &mut rng, &mut []),
+            0
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "minimum distance must be greater than 0")]
+    fn zero_distance_panics() {
+        let mut rng = TestRng(7);
+        let _ = poisson_disk(Rect::from_ltwh(0, 0, 5, 5), 0, &mut rng, &mut [Pos::ORIGIN]);
+    }
+}
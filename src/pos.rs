@@ -1,4 +1,4 @@
-use core::{fmt::Display, ops};
+use core::{fmt::Display, ops, str::FromStr};
 
 use crate::{
     Size,
@@ -72,6 +72,7 @@ macro_rules! pos {
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 #[repr(C)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct Pos<T = i32> {
     /// The x-coordinate, or _horizontal_ position from the origin.
     ///
@@ -308,6 +309,130 @@ impl<T: Int> Pos<T> {
     pub fn cmp_lexicographic(&self, other: &Self) -> core::cmp::Ordering {
         self.x.cmp(&other.x).then(self.y.cmp(&other.y))
     }
+
+    /// Rounds each coordinate down (towards negative infinity) to a multiple of `step`.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `step` is `0`.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use ixy::Pos;
+    ///
+    /// assert_eq!(Pos::new(7, -7).snap_down(4), Pos::new(4, -8));
+    /// assert_eq!(Pos::new(8, 0).snap_down(4), Pos::new(8, 0));
+    /// ```
+    #[must_use]
+    pub fn snap_down(&self, step: T) -> Self {
+        Self {
+            x: self.x - self.x.rem_euclid(step),
+            y: self.y - self.y.rem_euclid(step),
+        }
+    }
+
+    /// Rounds each coordinate up (towards positive infinity) to a multiple of `step`.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `step` is `0`.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use ixy::Pos;
+    ///
+    /// assert_eq!(Pos::new(7, -7).snap_up(4), Pos::new(8, -4));
+    /// assert_eq!(Pos::new(8, 0).snap_up(4), Pos::new(8, 0));
+    /// ```
+    #[must_use]
+    pub fn snap_up(&self, step: T) -> Self {
+        Self {
+            x: internal::snap_up(self.x, step),
+            y: internal::snap_up(self.y, step),
+        }
+    }
+
+    /// Interpolates from `a` (at `num = 0`) to `b` (at `num = den`).
+    ///
+    /// Each coordinate is rounded to the nearest integer, with ties rounded away from `a`, so
+    /// `num == den` lands exactly on `b`.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `den` is `0`, or if `num > den` and the result cannot be represented by `T`.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use ixy::Pos;
+    ///
+    /// let a = Pos::new(0, 10);
+    /// let b = Pos::new(10, 0);
+    /// assert_eq!(Pos::lerp(a, b, 1, 4), Pos::new(3, 7));
+    /// assert_eq!(Pos::lerp(a, b, 4, 4), b);
+    /// ```
+    #[must_use]
+    pub fn lerp(a: Self, b: Self, num: usize, den: usize) -> Self {
+        Self {
+            x: internal::lerp(a.x, b.x, num, den),
+            y: internal::lerp(a.y, b.y, num, den),
+        }
+    }
+
+    /// Divides each coordinate by the matching coordinate of `rhs`, rounding towards negative
+    /// infinity for positive divisors.
+    ///
+    /// Unlike [`ops::Div`], negative coordinates stay in the correct cell, which makes this the
+    /// right choice for mapping world positions to cells of a per-axis size.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if either coordinate of `rhs` is `0`.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use ixy::Pos;
+    ///
+    /// let cell = Pos::new(16, 8);
+    /// assert_eq!(Pos::new(17, -1).div_euclid_pos(cell), Pos::new(1, -1));
+    /// assert_eq!(Pos::new(17, -1) / cell, Pos::new(1, 0));
+    /// ```
+    #[must_use]
+    pub fn div_euclid_pos(&self, rhs: Self) -> Self {
+        Self {
+            x: self.x.div_euclid(rhs.x),
+            y: self.y.div_euclid(rhs.y),
+        }
+    }
+
+    /// Returns the non-negative remainder of dividing each coordinate by the matching coordinate
+    /// of `rhs`, for positive divisors.
+    ///
+    /// This is the offset of a position within its cell; see [`Pos::div_euclid_pos`].
+    ///
+    /// ## Panics
+    ///
+    /// Panics if either coordinate of `rhs` is `0`.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use ixy::Pos;
+    ///
+    /// let cell = Pos::new(16, 8);
+    /// assert_eq!(Pos::new(17, -1).rem_euclid_pos(cell), Pos::new(1, 7));
+    /// assert_eq!(Pos::new(17, -1) % cell, Pos::new(1, -1));
+    /// ```
+    #[must_use]
+    pub fn rem_euclid_pos(&self, rhs: Self) -> Self {
+        Self {
+            x: self.x.rem_euclid(rhs.x),
+            y: self.y.rem_euclid(rhs.y),
+        }
+    }
 }
 
 impl<T: SignedInt> Pos<T> {
@@ -356,6 +481,57 @@ impl<T: SignedInt> Pos<T> {
         x: T::ZERO,
         y: T::NEG_ONE,
     };
+
+    /// Reflects the position through `pivot`, i.e. rotates it 180 degrees around `pivot`.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use ixy::Pos;
+    ///
+    /// assert_eq!(Pos::new(1, 2).reflect_across(Pos::new(3, 3)), Pos::new(5, 4));
+    /// ```
+    #[must_use]
+    pub fn reflect_across(&self, pivot: Self) -> Self {
+        Self {
+            x: pivot.x - (self.x - pivot.x),
+            y: pivot.y - (self.y - pivot.y),
+        }
+    }
+
+    /// Reflects the position across the vertical line at `axis_x`, keeping `y` unchanged.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use ixy::Pos;
+    ///
+    /// assert_eq!(Pos::new(2, 5).reflect_x(4), Pos::new(6, 5));
+    /// ```
+    #[must_use]
+    pub fn reflect_x(&self, axis_x: T) -> Self {
+        Self {
+            x: axis_x - (self.x - axis_x),
+            y: self.y,
+        }
+    }
+
+    /// Reflects the position across the horizontal line at `axis_y`, keeping `x` unchanged.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use ixy::Pos;
+    ///
+    /// assert_eq!(Pos::new(2, 5).reflect_y(0), Pos::new(2, -5));
+    /// ```
+    #[must_use]
+    pub fn reflect_y(&self, axis_y: T) -> Self {
+        Self {
+            x: self.x,
+            y: axis_y - (self.y - axis_y),
+        }
+    }
 }
 
 impl<T: Int> Display for Pos<T> {
@@ -364,6 +540,46 @@ impl<T: Int> Display for Pos<T> {
     }
 }
 
+/// Error returned when parsing a [`Pos`] from a string fails.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParsePosError;
+
+impl Display for ParsePosError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("invalid position, expected `x,y`")
+    }
+}
+
+impl core::error::Error for ParsePosError {}
+
+impl<T: Int + FromStr> FromStr for Pos<T> {
+    type Err = ParsePosError;
+
+    /// Parses a position in the form `x,y`, optionally wrapped in parentheses.
+    ///
+    /// Whitespace around each number (and around the parentheses) is ignored, so the output of
+    /// [`Display`] can be parsed back.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use ixy::Pos;
+    ///
+    /// assert_eq!("10,-20".parse(), Ok(Pos::new(10, -20)));
+    /// assert_eq!("(10, -20)".parse(), Ok(Pos::new(10, -20)));
+    /// assert!("10".parse::<Pos>().is_err());
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let s = s
+            .strip_prefix('(')
+            .and_then(|s| s.strip_suffix(')'))
+            .unwrap_or(s);
+        let (x, y) = internal::parse_pair(s).ok_or(ParsePosError)?;
+        Ok(Self { x, y })
+    }
+}
+
 impl<T: Int> PartialOrd for Pos<T> {
     fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
         Some(self.cmp(other))
@@ -505,6 +721,42 @@ impl<T: Int> ops::DivAssign<Self> for Pos<T> {
     }
 }
 
+impl<T: Int> ops::Rem<T> for Pos<T> {
+    type Output = Self;
+
+    fn rem(self, rhs: T) -> Self::Output {
+        Self {
+            x: self.x % rhs,
+            y: self.y % rhs,
+        }
+    }
+}
+
+impl<T: Int> ops::RemAssign<T> for Pos<T> {
+    fn rem_assign(&mut self, rhs: T) {
+        self.x %= rhs;
+        self.y %= rhs;
+    }
+}
+
+impl<T: Int> ops::Rem<Self> for Pos<T> {
+    type Output = Self;
+
+    fn rem(self, rhs: Self) -> Self::Output {
+        Self {
+            x: self.x % rhs.x,
+            y: self.y % rhs.y,
+        }
+    }
+}
+
+impl<T: Int> ops::RemAssign<Self> for Pos<T> {
+    fn rem_assign(&mut self, rhs: Self) {
+        self.x %= rhs.x;
+        self.y %= rhs.y;
+    }
+}
+
 impl<T: Int> From<(T, T)> for Pos<T> {
     fn from(value: (T, T)) -> Self {
         Self::new(value.0, value.1)
@@ -529,6 +781,56 @@ impl<T: Int> From<Pos<T>> for [T; 2] {
     }
 }
 
+/// Converts a `nalgebra` point into a position.
+///
+/// ## Examples
+///
+/// ```rust
+/// use ixy::Pos;
+/// use nalgebra::Point2;
+///
+/// assert_eq!(Pos::from(Point2::new(1, 2)), Pos::new(1, 2));
+/// assert_eq!(Point2::from(Pos::new(1, 2)), Point2::new(1, 2));
+/// ```
+#[cfg(feature = "nalgebra")]
+impl<T: Int + nalgebra::Scalar> From<nalgebra::Point2<T>> for Pos<T> {
+    fn from(point: nalgebra::Point2<T>) -> Self {
+        Self::new(point.x, point.y)
+    }
+}
+
+#[cfg(feature = "nalgebra")]
+impl<T: Int + nalgebra::Scalar> From<Pos<T>> for nalgebra::Point2<T> {
+    fn from(pos: Pos<T>) -> Self {
+        Self::new(pos.x, pos.y)
+    }
+}
+
+/// Converts a `nalgebra` vector into a position.
+///
+/// ## Examples
+///
+/// ```rust
+/// use ixy::Pos;
+/// use nalgebra::Vector2;
+///
+/// assert_eq!(Pos::from(Vector2::new(1, 2)), Pos::new(1, 2));
+/// assert_eq!(Vector2::from(Pos::new(1, 2)), Vector2::new(1, 2));
+/// ```
+#[cfg(feature = "nalgebra")]
+impl<T: Int + nalgebra::Scalar> From<nalgebra::Vector2<T>> for Pos<T> {
+    fn from(vector: nalgebra::Vector2<T>) -> Self {
+        Self::new(vector.x, vector.y)
+    }
+}
+
+#[cfg(feature = "nalgebra")]
+impl<T: Int + nalgebra::Scalar> From<Pos<T>> for nalgebra::Vector2<T> {
+    fn from(pos: Pos<T>) -> Self {
+        Self::new(pos.x, pos.y)
+    }
+}
+
 /// A trait for converting a `Pos<T>` to another type.
 pub trait TryFromPos<T: Int>: Sized {
     /// Returns the type that the `Pos<T>` can be converted to.
@@ -945,4 +1247,97 @@ mod tests {
         assert_eq!(p.x, 1i32);
         assert_eq!(p.y, 2i32);
     }
+
+    #[test]
+    fn snap_down_positive_and_negative() {
+        assert_eq!(Pos::new(5, 3).snap_down(4), Pos::new(4, 0));
+        assert_eq!(Pos::new(-1, -4).snap_down(4), Pos::new(-4, -4));
+        assert_eq!(Pos::<u8>::new(9, 15).snap_down(8), Pos::new(8, 8));
+    }
+
+    #[test]
+    fn snap_up_positive_and_negative() {
+        assert_eq!(Pos::new(5, 4).snap_up(4), Pos::new(8, 4));
+        assert_eq!(Pos::new(-1, -5).snap_up(4), Pos::new(0, -4));
+    }
+
+    #[test]
+    #[should_panic(expected = "divisor of zero")]
+    fn snap_zero_step_panics() {
+        let _ = Pos::new(1, 1).snap_down(0);
+    }
+
+    #[test]
+    fn rem_by_scalar_and_pos() {
+        assert_eq!(Pos::new(7, -7) % 4, Pos::new(3, -3));
+        let mut p = Pos::new(7u8, 9);
+        p %= Pos::new(4, 5);
+        assert_eq!(p, Pos::new(3, 4));
+    }
+
+    #[test]
+    fn euclid_pos_round_trip() {
+        let cell = Pos::new(3, 5);
+        for p in [Pos::new(-7, 11), Pos::new(0, -1), Pos::new(5, 5)] {
+            let r = p.rem_euclid_pos(cell);
+            assert!(r.x >= 0 && r.x < cell.x && r.y >= 0 && r.y < cell.y);
+            assert_eq!(p.div_euclid_pos(cell) * cell + r, p);
+        }
+    }
+
+    #[test]
+    fn reflect_near_max() {
+        assert_eq!(Pos::new(100i8, 0).reflect_x(100), Pos::new(100, 0));
+        assert_eq!(Pos::new(0, 120i8).reflect_y(100), Pos::new(0, 80));
+        assert_eq!(
+            Pos::new(i8::MAX, -90).reflect_across(Pos::new(100, -100)),
+            Pos::new(73, -110)
+        );
+    }
+
+    #[test]
+    fn reflect_is_involution() {
+        let p = Pos::new(-3, 7);
+        let pivot = Pos::new(2, -1);
+        assert_eq!(p.reflect_across(pivot).reflect_across(pivot), p);
+        assert_eq!(p.reflect_x(2).reflect_x(2), p);
+        assert_eq!(p.reflect_y(-1).reflect_y(-1), p);
+        assert_eq!(
+            p.reflect_x(pivot.x).reflect_y(pivot.y),
+            p.reflect_across(pivot)
+        );
+    }
+
+    #[test]
+    fn from_str() {
+        assert_eq!("1,2".parse(), Ok(Pos::new(1, 2)));
+        assert_eq!(" ( -1 , 2 ) ".parse(), Ok(Pos::new(-1, 2)));
+        assert_eq!("(3,4)".parse::<Pos<u8>>(), Ok(Pos::new(3, 4)));
+    }
+
+    #[test]
+    fn from_str_display_round_trip() {
+        extern crate alloc;
+        use alloc::string::ToString;
+
+        let pos = Pos::new(-7, 8);
+        assert_eq!(pos.to_string().parse(), Ok(pos));
+    }
+
+    #[test]
+    fn from_str_invalid() {
+        for s in [
+            "", "1", "1,", "(1,2", "1,2)", "((1,2))", "1,2,3", "a,b", "-1,0",
+        ] {
+            assert_eq!(s.parse::<Pos<u8>>(), Err(ParsePosError), "{s:?}");
+        }
+    }
+
+    #[test]
+    fn lerp_straddling_zero() {
+        let a = Pos::new(-100i8, 127);
+        let b = Pos::new(100i8, -128);
+        assert_eq!(Pos::lerp(a, b, 1, 2), Pos::new(0, -1));
+        assert_eq!(Pos::lerp(a, b, 2, 2), b);
+    }
 }
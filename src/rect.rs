@@ -1,9 +1,11 @@
-use core::{fmt::Display, ops};
+use core::{fmt::Display, ops, str::FromStr};
 
 use crate::{
-    HasSize, Pos, Size,
+    Align2, Direction, HasSize, Insets, Pos, Segment, Size, Span, TryIntoSize,
     int::Int,
+    internal,
     layout::{RowMajor, Traversal},
+    ops::cell,
 };
 
 /// A macro that creates a rectangle with the given coordinates.
@@ -61,6 +63,7 @@ macro_rules! rect {
 #[repr(C)]
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct Rect<T = i32> {
     x: T,
     y: T,
@@ -73,6 +76,58 @@ pub struct Rect<T = i32> {
 pub enum RectError {
     /// The dimensions provided do not form a valid rectangle.
     InvalidDimensions,
+
+    /// The string being parsed is not in a supported format.
+    InvalidFormat,
+}
+
+impl Display for RectError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::InvalidDimensions => f.write_str("invalid rectangle dimensions"),
+            Self::InvalidFormat => {
+                f.write_str("invalid rectangle, expected `l,t,w,h` or `l,t..r,b`")
+            }
+        }
+    }
+}
+
+impl core::error::Error for RectError {}
+
+/// Where a position lies relative to the resize handles of a rectangle.
+///
+/// See [`Rect::hit_test`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HitZone {
+    /// Outside of the rectangle.
+    Outside,
+
+    /// Inside the rectangle, but not on any handle.
+    Interior,
+
+    /// On the top edge handle.
+    Top,
+
+    /// On the bottom edge handle.
+    Bottom,
+
+    /// On the left edge handle.
+    Left,
+
+    /// On the right edge handle.
+    Right,
+
+    /// On the top-left corner handle.
+    TopLeft,
+
+    /// On the top-right corner handle.
+    TopRight,
+
+    /// On the bottom-left corner handle.
+    BottomLeft,
+
+    /// On the bottom-right corner handle.
+    BottomRight,
 }
 
 impl<T: Int> Rect<T> {
@@ -118,6 +173,42 @@ impl<T: Int> Rect<T> {
         Self::from_ltwh(top_left.x, top_left.y, size.width, size.height)
     }
 
+    /// Creates a rectangle from a top-left corner position and size.
+    ///
+    /// Unlike [`Rect::from_tl_size`], the size is checked to fit in `T`, and the bottom-right
+    /// corner is checked to not overflow `T`.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an error if the width or height is negative or cannot be represented by `T`, or if
+    /// the bottom-right corner cannot be represented by `T`.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use ixy::{Pos, Rect, Size};
+    ///
+    /// let rect = Rect::from_pos_size(Pos::new(1, 2), Size::new(3, 4)).unwrap();
+    /// assert_eq!(rect, Rect::from_ltwh(1, 2, 3, 4));
+    ///
+    /// assert!(Rect::from_pos_size(Pos::new(200u8, 0), Size::new(100, 1)).is_err());
+    /// assert!(Rect::from_pos_size(Pos::new(0, 0), (-1, 2)).is_err());
+    /// ```
+    pub fn from_pos_size(pos: Pos<T>, size: impl TryIntoSize) -> Result<Self, RectError> {
+        let size = size.try_into_size().ok_or(RectError::InvalidDimensions)?;
+        let w = T::checked_from_usize(size.width).ok_or(RectError::InvalidDimensions)?;
+        let h = T::checked_from_usize(size.height).ok_or(RectError::InvalidDimensions)?;
+        if pos.x > T::MAX - w || pos.y > T::MAX - h {
+            return Err(RectError::InvalidDimensions);
+        }
+        Ok(Self {
+            x: pos.x,
+            y: pos.y,
+            w,
+            h,
+        })
+    }
+
     /// Creates a new rectangle from the top-left and bottom-right corners.
     ///
     /// ## Errors
@@ -181,8 +272,12 @@ impl<T: Int> Rect<T> {
     /// Creates a new rectangle from the `l`eft, `t`op, `r`ight, and `b`ottom coordinates.
     ///
     /// The caller must ensure `l <= r` and `t <= b`; in debug builds this is checked.
+    #[track_caller]
     pub fn from_ltrb_unchecked(l: T, t: T, r: T, b: T) -> Self {
-        debug_assert!(l <= r && t <= b);
+        debug_assert!(
+            l <= r && t <= b,
+            "invalid rectangle: l={l:?}, t={t:?}, r={r:?}, b={b:?}"
+        );
         Self {
             x: l,
             y: t,
@@ -191,6 +286,19 @@ impl<T: Int> Rect<T> {
         }
     }
 
+    /// Asserts, in debug builds, that the rectangle does not have a negative width or height.
+    ///
+    /// Used by operations that cannot report an error, so an invalid rectangle is caught where it
+    /// is created rather than when it is later iterated.
+    #[track_caller]
+    fn debug_validated(self) -> Self {
+        debug_assert!(
+            self.w >= T::ZERO && self.h >= T::ZERO,
+            "invalid rectangle: {self:?}"
+        );
+        self
+    }
+
     /// Creates a new rectangle from the `l`eft and `t`op coordinates, and `w`idth and `h`eight.
     ///
     /// ## Examples
@@ -213,6 +321,34 @@ impl<T: Int> Rect<T> {
         }
     }
 
+    /// Creates a new rectangle from the `l`eft and `t`op coordinates, and `w`idth and `h`eight,
+    /// where the dimensions are in the coordinate type `T`.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an error if the width or height is negative, or if the bottom-right corner cannot be
+    /// represented by `T`.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use ixy::Rect;
+    ///
+    /// let rect = Rect::from_ltwh_t(-10i64, 20, 1 << 40, 5).unwrap();
+    /// assert_eq!(rect.right(), (1 << 40) - 10);
+    /// assert_eq!(rect.height(), 5);
+    ///
+    /// assert!(Rect::from_ltwh_t(0, 0, -1, 5).is_err());
+    /// assert!(Rect::from_ltwh_t(250u8, 0, 10, 1).is_err());
+    /// ```
+    pub fn from_ltwh_t(l: T, t: T, w: T, h: T) -> Result<Self, RectError> {
+        if w < T::ZERO || h < T::ZERO || l > T::MAX - w || t > T::MAX - h {
+            Err(RectError::InvalidDimensions)
+        } else {
+            Ok(Self { x: l, y: t, w, h })
+        }
+    }
+
     /// Returns the top, or y-coordinate of the top edge of the rectangle.
     pub const fn top(&self) -> T {
         self.y
@@ -289,6 +425,94 @@ impl<T: Int> Rect<T> {
         Pos::new(self.x, self.y + self.h)
     }
 
+    /// Returns the center of the rectangle as a [`Pos<T>`].
+    ///
+    /// Each coordinate is `left + width / 2` (or `top + height / 2`), rounding towards the
+    /// top-left. For an odd width, this is the middle cell; for an even width, it is the right of
+    /// the two middle cells, i.e. the boundary between them.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use ixy::{Rect, Pos};
+    ///
+    /// assert_eq!(Rect::from_ltwh(0, 0, 3, 3).center(), Pos::new(1, 1));
+    /// assert_eq!(Rect::from_ltwh(0, 0, 4, 5).center(), Pos::new(2, 2));
+    /// ```
+    #[must_use]
+    pub fn center(&self) -> Pos<T> {
+        let two = T::ONE + T::ONE;
+        Pos::new(self.x + self.w / two, self.y + self.h / two)
+    }
+
+    /// Returns the center of the left edge of the rectangle as a [`Pos<T>`].
+    ///
+    /// Rounds like [`Rect::center`].
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use ixy::{Rect, Pos};
+    ///
+    /// let rect = Rect::from_ltrb(1, 2, 5, 6).unwrap();
+    /// assert_eq!(rect.center_left(), Pos::new(1, 4));
+    /// ```
+    #[must_use]
+    pub fn center_left(&self) -> Pos<T> {
+        Pos::new(self.x, self.center().y)
+    }
+
+    /// Returns the center of the right edge of the rectangle as a [`Pos<T>`].
+    ///
+    /// Like [`Rect::top_right`], the right edge is exclusive. Rounds like [`Rect::center`].
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use ixy::{Rect, Pos};
+    ///
+    /// let rect = Rect::from_ltrb(1, 2, 5, 6).unwrap();
+    /// assert_eq!(rect.center_right(), Pos::new(5, 4));
+    /// ```
+    #[must_use]
+    pub fn center_right(&self) -> Pos<T> {
+        Pos::new(self.right(), self.center().y)
+    }
+
+    /// Returns the center of the top edge of the rectangle as a [`Pos<T>`].
+    ///
+    /// Rounds like [`Rect::center`].
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use ixy::{Rect, Pos};
+    ///
+    /// let rect = Rect::from_ltrb(1, 2, 5, 6).unwrap();
+    /// assert_eq!(rect.center_top(), Pos::new(3, 2));
+    /// ```
+    #[must_use]
+    pub fn center_top(&self) -> Pos<T> {
+        Pos::new(self.center().x, self.y)
+    }
+
+    /// Returns the center of the bottom edge of the rectangle as a [`Pos<T>`].
+    ///
+    /// Like [`Rect::bottom_left`], the bottom edge is exclusive. Rounds like [`Rect::center`].
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use ixy::{Rect, Pos};
+    ///
+    /// let rect = Rect::from_ltrb(1, 2, 5, 6).unwrap();
+    /// assert_eq!(rect.center_bottom(), Pos::new(3, 6));
+    /// ```
+    #[must_use]
+    pub fn center_bottom(&self) -> Pos<T> {
+        Pos::new(self.center().x, self.bottom())
+    }
+
     /// Returns the width of the rectangle.
     ///
     /// ## Examples
@@ -327,6 +551,84 @@ impl<T: Int> Rect<T> {
         self.h.to_usize()
     }
 
+    /// Creates a rectangle from a horizontal and a vertical span.
+    ///
+    /// An empty span results in an empty rectangle.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use ixy::{Rect, Span};
+    ///
+    /// let rect = Rect::from_spans(Span::new(1, 4), Span::new(2, 6));
+    /// assert_eq!(rect, Rect::from_ltrb(1, 2, 4, 6).unwrap());
+    /// ```
+    #[must_use]
+    pub fn from_spans(x: Span<T>, y: Span<T>) -> Self {
+        Self {
+            x: x.start,
+            y: y.start,
+            w: x.len(),
+            h: y.len(),
+        }
+    }
+
+    /// Returns the horizontal span of the rectangle, from its left to its right edge.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use ixy::{Rect, Span};
+    ///
+    /// let rect = Rect::from_ltrb(1, 2, 4, 6).unwrap();
+    /// assert_eq!(rect.x_span(), Span::new(1, 4));
+    /// ```
+    #[must_use]
+    pub fn x_span(&self) -> Span<T> {
+        Span::new(self.left(), self.right())
+    }
+
+    /// Returns the vertical span of the rectangle, from its top to its bottom edge.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use ixy::{Rect, Span};
+    ///
+    /// let rect = Rect::from_ltrb(1, 2, 4, 6).unwrap();
+    /// assert_eq!(rect.y_span(), Span::new(2, 6));
+    /// ```
+    #[must_use]
+    pub fn y_span(&self) -> Span<T> {
+        Span::new(self.top(), self.bottom())
+    }
+
+    /// Returns the rows of the rectangle, from top to bottom, as spans of `(y, x_start..x_end)`.
+    ///
+    /// Produces the same shape of output as [`LineExt::spans`](crate::ops::line::LineExt::spans)
+    /// and [`circle::filled_spans`](crate::ops::circle::filled_spans), so filling a rectangle can
+    /// share code with filling other shapes. An empty rectangle yields no spans.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use ixy::{Rect, Span};
+    ///
+    /// let rect = Rect::from_ltrb(1, 2, 4, 4).unwrap();
+    /// let spans: Vec<_> = rect.row_spans().collect();
+    /// assert_eq!(spans, &[(2, Span::new(1, 4)), (3, Span::new(1, 4))]);
+    /// ```
+    pub fn row_spans(&self) -> impl ExactSizeIterator<Item = (T, Span<T>)> + use<T> {
+        let top = self.top();
+        let x_span = self.x_span();
+        let rows = if self.is_empty() {
+            0
+        } else {
+            self.height_usize()
+        };
+        (0..rows).map(move |i| (top + T::from_usize(i), x_span))
+    }
+
     /// Returns `true` if the rectangle is empty, i.e., if its width or height is zero.
     pub fn is_empty(&self) -> bool {
         self.w == T::ZERO || self.h == T::ZERO
@@ -346,6 +648,50 @@ impl<T: Int> Rect<T> {
         self.width_usize() * self.height_usize()
     }
 
+    /// Returns the area of the rectangle as a [`u64`], independent of the platform pointer size.
+    ///
+    /// Returns [`None`] if the area cannot be represented by a [`u64`].
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use ixy::Rect;
+    ///
+    /// let rect = Rect::from_ltrb(0i64, 0, 1 << 20, 1 << 20).unwrap();
+    /// assert_eq!(rect.area_u64(), Some(1 << 40));
+    ///
+    /// let rect = Rect::from_ltrb(0u128, 0, 1 << 64, 1).unwrap();
+    /// assert_eq!(rect.area_u64(), None);
+    /// ```
+    #[must_use]
+    pub fn area_u64(&self) -> Option<u64> {
+        self.w
+            .checked_to_u64()?
+            .checked_mul(self.h.checked_to_u64()?)
+    }
+
+    /// Returns the perimeter of the rectangle, which is twice the sum of its width and height.
+    ///
+    /// Saturates at [`usize::MAX`] instead of overflowing.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use ixy::Rect;
+    ///
+    /// let rect = Rect::from_ltrb(1, 2, 4, 4).unwrap();
+    /// assert_eq!(rect.perimeter(), 10);
+    ///
+    /// let rect = Rect::from_ltrb(0usize, 0, usize::MAX, 1).unwrap();
+    /// assert_eq!(rect.perimeter(), usize::MAX);
+    /// ```
+    #[must_use]
+    pub fn perimeter(&self) -> usize {
+        self.width_usize()
+            .saturating_add(self.height_usize())
+            .saturating_mul(2)
+    }
+
     /// Returns `true` if the rectangle contains the given `x` and `y` coordinates.
     ///
     /// ## Examples
@@ -443,6 +789,61 @@ impl<T: Int> Rect<T> {
         }
     }
 
+    /// Returns `true` if the rectangles overlap in at least one position.
+    ///
+    /// Equivalent to `!self.intersect(other).is_empty()`, without building the intersection. An
+    /// empty rectangle never intersects anything.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use ixy::Rect;
+    ///
+    /// let a = Rect::from_ltrb(1, 2, 5, 6).unwrap();
+    /// assert!(a.intersects(Rect::from_ltrb(3, 4, 7, 8).unwrap()));
+    /// assert!(!a.intersects(Rect::from_ltrb(5, 2, 7, 6).unwrap()));
+    /// assert!(!a.intersects(Rect::from_ltrb(2, 3, 2, 5).unwrap()));
+    /// ```
+    #[must_use]
+    pub fn intersects(&self, other: Self) -> bool {
+        self.x.max(other.x) < (self.x + self.w).min(other.x + other.w)
+            && self.y.max(other.y) < (self.y + self.h).min(other.y + other.h)
+    }
+
+    /// Returns the smallest rectangle that contains both rectangles.
+    ///
+    /// Empty rectangles are ignored; if both are empty, returns `self`.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use ixy::Rect;
+    ///
+    /// let a = Rect::from_ltrb(1, 2, 5, 6).unwrap();
+    /// let b = Rect::from_ltrb(3, 4, 7, 8).unwrap();
+    /// assert_eq!(a.union(b), Rect::from_ltrb(1, 2, 7, 8).unwrap());
+    /// assert_eq!(a.union(Rect::EMPTY), a);
+    /// ```
+    #[must_use]
+    pub fn union(&self, other: Self) -> Self {
+        if other.is_empty() {
+            return *self;
+        }
+        if self.is_empty() {
+            return other;
+        }
+        let l = core::cmp::min(self.x, other.x);
+        let t = core::cmp::min(self.y, other.y);
+        let r = core::cmp::max(self.x + self.w, other.x + other.w);
+        let b = core::cmp::max(self.y + self.h, other.y + other.h);
+        Self {
+            x: l,
+            y: t,
+            w: r - l,
+            h: b - t,
+        }
+    }
+
     /// Returns an iterator over the positions in the rectangle.
     ///
     /// The positions are returned in row-major order, starting from the top-left corner.
@@ -479,19 +880,1251 @@ impl<T: Int> Rect<T> {
             h: self.h,
         }
     }
-}
 
-impl<T: Display + Int> Display for Rect<T> {
-    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        write!(f, "Rect({}, {}, {}, {})", self.x, self.y, self.w, self.h)
+    /// Returns the smallest rectangle aligned to a grid of `cell_size` that covers this rectangle.
+    ///
+    /// The edges are expanded outwards to the nearest multiple of the cell size, rounding towards
+    /// negative infinity for the top-left corner and positive infinity for the bottom-right.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `cell_size` has a width or height of `0`.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use ixy::{Rect, Size};
+    ///
+    /// let rect = Rect::from_ltrb(-3, 5, 9, 8).unwrap();
+    /// let snapped = rect.snap_to_grid(Size::new(4, 4));
+    /// assert_eq!(snapped, Rect::from_ltrb(-4, 4, 12, 8).unwrap());
+    /// ```
+    #[must_use]
+    pub fn snap_to_grid(&self, cell_size: Size) -> Self {
+        cell::cells_to_rect(cell::rect_to_cells(*self, cell_size), cell_size)
     }
-}
 
-impl<T: Int> HasSize for Rect<T> {
-    fn size(&self) -> Size {
-        Size {
-            width: self.width_usize(),
-            height: self.height_usize(),
+    /// Returns an iterator that splits the rectangle into `n` columns of (nearly) equal width.
+    ///
+    /// The widths of the columns differ by at most one cell; the first `width % n` columns are
+    /// one cell wider than the rest. If `n` is greater than the width, the trailing columns are
+    /// empty, and if `n` is `0`, no columns are yielded.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use ixy::Rect;
+    ///
+    /// let rect = Rect::from_ltwh(0, 0, 10, 2);
+    /// let columns: Vec<_> = rect.split_columns(3).collect();
+    /// assert_eq!(
+    ///     columns,
+    ///     &[
+    ///         Rect::from_ltwh(0, 0, 4, 2),
+    ///         Rect::from_ltwh(4, 0, 3, 2),
+    ///         Rect::from_ltwh(7, 0, 3, 2),
+    ///     ]
+    /// );
+    /// ```
+    pub fn split_columns(&self, n: usize) -> impl Iterator<Item = Self> {
+        let rect = *self;
+        let (base, extra) = split_evenly(self.width_usize(), n);
+        (0..n).map(move |i| {
+            let offset = i * base + i.min(extra);
+            let width = base + usize::from(i < extra);
+            Self::from_ltwh(
+                rect.x + T::from_usize(offset),
+                rect.y,
+                width,
+                rect.height_usize(),
+            )
+        })
+    }
+
+    /// Returns an iterator that splits the rectangle into `n` rows of (nearly) equal height.
+    ///
+    /// The heights of the rows differ by at most one cell; the first `height % n` rows are one
+    /// cell taller than the rest. If `n` is greater than the height, the trailing rows are empty,
+    /// and if `n` is `0`, no rows are yielded.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use ixy::Rect;
+    ///
+    /// let rect = Rect::from_ltwh(0, 0, 2, 5);
+    /// let rows: Vec<_> = rect.split_rows(2).collect();
+    /// assert_eq!(
+    ///     rows,
+    ///     &[Rect::from_ltwh(0, 0, 2, 3), Rect::from_ltwh(0, 3, 2, 2)]
+    /// );
+    /// ```
+    pub fn split_rows(&self, n: usize) -> impl Iterator<Item = Self> {
+        let rect = *self;
+        let (base, extra) = split_evenly(self.height_usize(), n);
+        (0..n).map(move |i| {
+            let offset = i * base + i.min(extra);
+            let height = base + usize::from(i < extra);
+            Self::from_ltwh(
+                rect.x,
+                rect.y + T::from_usize(offset),
+                rect.width_usize(),
+                height,
+            )
+        })
+    }
+
+    /// Classifies a position relative to the rectangle's resize handles.
+    ///
+    /// Positions within `handle_thickness` of an edge (inside the rectangle) are on that edge, and
+    /// positions within `handle_thickness` of two adjacent edges are on the corner between them. If
+    /// the rectangle is too small for opposite handles to be disjoint, the top and left handles
+    /// take precedence.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use ixy::{HitZone, Pos, Rect};
+    ///
+    /// let window = Rect::from_ltwh(0, 0, 10, 10);
+    /// assert_eq!(window.hit_test(Pos::new(0, 0), 2), HitZone::TopLeft);
+    /// assert_eq!(window.hit_test(Pos::new(5, 9), 2), HitZone::Bottom);
+    /// assert_eq!(window.hit_test(Pos::new(5, 5), 2), HitZone::Interior);
+    /// assert_eq!(window.hit_test(Pos::new(10, 5), 2), HitZone::Outside);
+    /// ```
+    #[must_use]
+    pub fn hit_test(&self, pos: Pos<T>, handle_thickness: usize) -> HitZone {
+        if !self.contains_pos(pos) {
+            return HitZone::Outside;
+        }
+        let left = (pos.x - self.left()).to_usize() < handle_thickness;
+        let top = (pos.y - self.top()).to_usize() < handle_thickness;
+        let right = !left && (self.right() - T::ONE - pos.x).to_usize() < handle_thickness;
+        let bottom = !top && (self.bottom() - T::ONE - pos.y).to_usize() < handle_thickness;
+        match (left, top, right, bottom) {
+            (true, true, _, _) => HitZone::TopLeft,
+            (_, true, true, _) => HitZone::TopRight,
+            (true, _, _, true) => HitZone::BottomLeft,
+            (_, _, true, true) => HitZone::BottomRight,
+            (true, _, _, _) => HitZone::Left,
+            (_, true, _, _) => HitZone::Top,
+            (_, _, true, _) => HitZone::Right,
+            (_, _, _, true) => HitZone::Bottom,
+            _ => HitZone::Interior,
+        }
+    }
+
+    /// Returns the rectangle extended by `n` cells towards `direction`.
+    ///
+    /// Only the edge facing `direction` moves; the opposite edge stays in place.
+    ///
+    /// Growth saturates instead of overflowing: the moving edge stops at `T::MIN` or `T::MAX`, and
+    /// the width or height stops at `T::MAX`.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use ixy::{Direction, Rect};
+    ///
+    /// let rect = Rect::from_ltrb(2, 2, 4, 4).unwrap();
+    /// assert_eq!(rect.grow_toward(Direction::Down, 3), Rect::from_ltrb(2, 2, 4, 7).unwrap());
+    /// assert_eq!(rect.grow_toward(Direction::Left, 1), Rect::from_ltrb(1, 2, 4, 4).unwrap());
+    ///
+    /// let rect = Rect::from_ltrb(2u8, 2, 4, 4).unwrap();
+    /// assert_eq!(rect.grow_toward(Direction::Up, 5), Rect::from_ltrb(2, 0, 4, 4).unwrap());
+    /// ```
+    #[must_use]
+    pub fn grow_toward(&self, direction: Direction, n: usize) -> Self {
+        let room = |a: T, b: T| usize::try_from(a.abs_diff_u128(b)).unwrap_or(usize::MAX);
+        let n = match direction {
+            Direction::Up => n.min(room(self.y, T::MIN)).min(room(self.h, T::MAX)),
+            Direction::Right => n.min(room(self.right(), T::MAX)).min(room(self.w, T::MAX)),
+            Direction::Down => n.min(room(self.bottom(), T::MAX)).min(room(self.h, T::MAX)),
+            Direction::Left => n.min(room(self.x, T::MIN)).min(room(self.w, T::MAX)),
+        };
+        let n = T::from_usize(n);
+        let mut rect = *self;
+        match direction {
+            Direction::Up => {
+                rect.y -= n;
+                rect.h += n;
+            }
+            Direction::Right => rect.w += n,
+            Direction::Down => rect.h += n,
+            Direction::Left => {
+                rect.x -= n;
+                rect.w += n;
+            }
+        }
+        rect
+    }
+
+    /// Returns the rectangle with `n` cells removed from the side facing `direction`.
+    ///
+    /// Only the edge facing `direction` moves; if `n` exceeds the width or height, the result is
+    /// empty, collapsed against the opposite edge.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use ixy::{Direction, Rect};
+    ///
+    /// let rect = Rect::from_ltrb(2, 2, 6, 6).unwrap();
+    /// assert_eq!(rect.shrink_from(Direction::Left, 1), Rect::from_ltrb(3, 2, 6, 6).unwrap());
+    /// assert_eq!(rect.shrink_from(Direction::Up, 9), Rect::from_ltrb(2, 6, 6, 6).unwrap());
+    /// ```
+    #[must_use]
+    pub fn shrink_from(&self, direction: Direction, n: usize) -> Self {
+        let mut rect = *self;
+        match direction {
+            Direction::Up => {
+                let n = T::from_usize(n.min(self.height_usize()));
+                rect.y += n;
+                rect.h -= n;
+            }
+            Direction::Right => rect.w -= T::from_usize(n.min(self.width_usize())),
+            Direction::Down => rect.h -= T::from_usize(n.min(self.height_usize())),
+            Direction::Left => {
+                let n = T::from_usize(n.min(self.width_usize()));
+                rect.x += n;
+                rect.w -= n;
+            }
+        }
+        rect
+    }
+
+    /// Returns the rectangle moved by `offset`, but clamped so that it stays inside `bounds`.
+    ///
+    /// Each axis is clamped independently, so sliding diagonally into an edge keeps moving along
+    /// it. The offset that was actually applied is `result.top_left() - self.top_left()`.
+    ///
+    /// A rectangle that starts outside `bounds` is moved inside; on an axis where the rectangle is
+    /// larger than `bounds`, it is aligned to the left or top edge of `bounds` instead.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use ixy::{Pos, Rect};
+    ///
+    /// let bounds = Rect::from_ltwh(0, 0, 10, 10);
+    /// let window = Rect::from_ltwh(6, 2, 3, 3);
+    ///
+    /// let moved = window.translate_within(Pos::new(5, -1), bounds);
+    /// assert_eq!(moved, Rect::from_ltwh(7, 1, 3, 3));
+    /// assert_eq!(moved.top_left() - window.top_left(), Pos::new(1, -1));
+    /// ```
+    #[must_use]
+    pub fn translate_within(&self, offset: Pos<T>, bounds: Self) -> Self {
+        let clamp = |value: T, size: T, lo: T, bound_size: T| {
+            if size >= bound_size {
+                lo
+            } else {
+                value.max(lo).min(lo + bound_size - size)
+            }
+        };
+        Self {
+            x: clamp(self.x + offset.x, self.w, bounds.x, bounds.w),
+            y: clamp(self.y + offset.y, self.h, bounds.y, bounds.h),
+            w: self.w,
+            h: self.h,
+        }
+    }
+
+    /// Returns the Minkowski sum of two rectangles: the bounds of every `a + b`, for each position
+    /// `a` in `self` and `b` in `other`.
+    ///
+    /// If either rectangle is empty, the result is an empty rectangle at `self.top_left() +
+    /// other.top_left()`.
+    ///
+    /// Useful for turning "does a box of size `S` at `p` overlap this rectangle?" into a single
+    /// [`Rect::contains_pos`] test, by summing with a rectangle of size `S` ending at the origin.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use ixy::{Pos, Rect, Size};
+    ///
+    /// let wall = Rect::from_ltwh(10, 0, 2, 10);
+    ///
+    /// // Top-left positions at which a 3x3 box overlaps the wall.
+    /// let zone = wall.minkowski_sum(Rect::from_ltwh(-2, -2, 3, 3));
+    /// assert_eq!(zone, Rect::from_ltwh(8, -2, 4, 12));
+    ///
+    /// let size = Size::new(3, 3);
+    /// for x in 5..15 {
+    ///     let p = Pos::new(x, 4);
+    ///     let hit = Rect::from_tl_size(p, size).intersects(wall);
+    ///     assert_eq!(zone.contains_pos(p), hit);
+    /// }
+    /// ```
+    #[must_use]
+    pub fn minkowski_sum(&self, other: Self) -> Self {
+        let x = self.x + other.x;
+        let y = self.y + other.y;
+        if self.is_empty() || other.is_empty() {
+            return Self {
+                x,
+                y,
+                w: T::ZERO,
+                h: T::ZERO,
+            };
+        }
+        Self {
+            x,
+            y,
+            w: self.w + other.w - T::ONE,
+            h: self.h + other.h - T::ONE,
+        }
+    }
+
+    /// Returns the Minkowski difference (erosion) of two rectangles: every position `p` for
+    /// which `other` translated by `p` fits entirely inside `self`.
+    ///
+    /// This is the counterpart to [`Rect::minkowski_sum`]. If `other` is wider or taller than
+    /// `self`, the result is an empty rectangle at `self.top_left() - other.top_left()`; if
+    /// `other` is empty, returns `self`.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use ixy::{Pos, Rect};
+    ///
+    /// // Top-left positions at which a 3x2 box stays inside the room.
+    /// let room = Rect::from_ltwh(0, 0, 10, 6);
+    /// let zone = room.minkowski_difference(Rect::from_ltwh(0, 0, 3, 2));
+    /// assert_eq!(zone, Rect::from_ltwh(0, 0, 8, 5));
+    /// assert!(room.contains_rect(Rect::from_ltwh(7, 4, 3, 2)));
+    /// assert!(!zone.contains_pos(Pos::new(8, 4)));
+    /// ```
+    #[must_use]
+    pub fn minkowski_difference(&self, other: Self) -> Self {
+        if other.is_empty() {
+            return *self;
+        }
+        let x = self.x - other.x;
+        let y = self.y - other.y;
+        if self.w < other.w || self.h < other.h {
+            return Self {
+                x,
+                y,
+                w: T::ZERO,
+                h: T::ZERO,
+            };
+        }
+        Self {
+            x,
+            y,
+            w: self.w - other.w + T::ONE,
+            h: self.h - other.h + T::ONE,
+        }
+    }
+
+    /// Returns the rectangle moved by `dx` horizontally and `dy` vertically.
+    ///
+    /// Equivalent to `self + Pos::new(dx, dy)`.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use ixy::Rect;
+    ///
+    /// let rect = Rect::from_ltwh(1, 2, 3, 4);
+    /// assert_eq!(rect.translate(10, -2), Rect::from_ltwh(11, 0, 3, 4));
+    /// ```
+    #[must_use]
+    pub fn translate(&self, dx: T, dy: T) -> Self {
+        *self + Pos::new(dx, dy)
+    }
+
+    /// Returns the rectangle moved by `dx` horizontally.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use ixy::Rect;
+    ///
+    /// let rect = Rect::from_ltwh(1, 2, 3, 4);
+    /// assert_eq!(rect.translate_x(-1), Rect::from_ltwh(0, 2, 3, 4));
+    /// ```
+    #[must_use]
+    pub fn translate_x(&self, dx: T) -> Self {
+        self.translate(dx, T::ZERO)
+    }
+
+    /// Returns the rectangle moved by `dy` vertically.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use ixy::Rect;
+    ///
+    /// let rect = Rect::from_ltwh(1, 2, 3, 4);
+    /// assert_eq!(rect.translate_y(5), Rect::from_ltwh(1, 7, 3, 4));
+    /// ```
+    #[must_use]
+    pub fn translate_y(&self, dy: T) -> Self {
+        self.translate(T::ZERO, dy)
+    }
+
+    /// Returns the rectangle moved by `dx` horizontally and `dy` vertically.
+    ///
+    /// Returns `None` if any edge of the moved rectangle would overflow `T`.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use ixy::Rect;
+    ///
+    /// let rect = Rect::from_ltwh(1u8, 2, 3, 4);
+    /// assert_eq!(rect.checked_translate(10, 2), Some(Rect::from_ltwh(11, 4, 3, 4)));
+    /// assert_eq!(rect.checked_translate(253, 0), None);
+    /// ```
+    #[must_use]
+    pub fn checked_translate(&self, dx: T, dy: T) -> Option<Self> {
+        let x = self.x.checked_add(dx)?;
+        let y = self.y.checked_add(dy)?;
+        x.checked_add(self.w)?;
+        y.checked_add(self.h)?;
+        Some(Self {
+            x,
+            y,
+            w: self.w,
+            h: self.h,
+        })
+    }
+
+    /// Returns the rectangle moved by `dx` horizontally.
+    ///
+    /// Returns `None` if the left or right edge of the moved rectangle would overflow `T`.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use ixy::Rect;
+    ///
+    /// let rect = Rect::from_ltwh(-1i8, 0, 3, 4);
+    /// assert_eq!(rect.checked_translate_x(-127), Some(Rect::from_ltwh(-128, 0, 3, 4)));
+    /// assert_eq!(rect.checked_translate_x(-128), None);
+    /// ```
+    #[must_use]
+    pub fn checked_translate_x(&self, dx: T) -> Option<Self> {
+        self.checked_translate(dx, T::ZERO)
+    }
+
+    /// Returns the rectangle moved by `dy` vertically.
+    ///
+    /// Returns `None` if the top or bottom edge of the moved rectangle would overflow `T`.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use ixy::Rect;
+    ///
+    /// let rect = Rect::from_ltwh(0u8, 250, 3, 4);
+    /// assert_eq!(rect.checked_translate_y(1), Some(Rect::from_ltwh(0, 251, 3, 4)));
+    /// assert_eq!(rect.checked_translate_y(2), None);
+    /// ```
+    #[must_use]
+    pub fn checked_translate_y(&self, dy: T) -> Option<Self> {
+        self.checked_translate(T::ZERO, dy)
+    }
+
+    /// Returns the rectangle grown by `n` on every side.
+    ///
+    /// Saturates in the same way as [`Rect::inflate_by`].
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use ixy::Rect;
+    ///
+    /// let rect = Rect::from_ltwh(2, 2, 4, 4);
+    /// assert_eq!(rect.inflate(2), Rect::from_ltwh(0, 0, 8, 8));
+    ///
+    /// let rect = Rect::from_ltwh(0u32, 0, 4, 4);
+    /// assert_eq!(rect.inflate(1), Rect::from_ltwh(0, 0, 5, 5));
+    /// ```
+    #[must_use]
+    pub fn inflate(&self, n: usize) -> Self {
+        self.inflate_by(Insets::uniform(n))
+    }
+
+    /// Returns the rectangle shrunk by `n` on every side.
+    ///
+    /// If the rectangle is too small, the result is empty instead of inverted.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use ixy::Rect;
+    ///
+    /// let rect = Rect::from_ltwh(2, 2, 4, 4);
+    /// assert_eq!(rect.deflate(1), Rect::from_ltwh(3, 3, 2, 2));
+    /// assert!(rect.deflate(3).is_empty());
+    /// ```
+    #[must_use]
+    pub fn deflate(&self, n: usize) -> Self {
+        self.deflate_by(Insets::uniform(n))
+    }
+
+    /// Returns the rectangle grown by the given distance on each side.
+    ///
+    /// Growth saturates like [`Rect::grow_toward`]: the left and top edges stop at `T::MIN`, then
+    /// the right and bottom edges stop at `T::MAX` or once the width or height reaches `T::MAX`.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use ixy::{Insets, Rect};
+    ///
+    /// let rect = Rect::from_ltwh(2, 2, 4, 4);
+    /// assert_eq!(rect.inflate_by(Insets::new(1, 0, 0, 2)), Rect::from_ltwh(1, 2, 5, 6));
+    ///
+    /// let rect = Rect::from_ltwh(1u8, 0, 4, 4);
+    /// assert_eq!(rect.inflate_by(Insets::new(3, 3, 0, 0)), Rect::from_ltwh(0, 0, 5, 4));
+    /// ```
+    #[must_use]
+    pub fn inflate_by(&self, insets: Insets) -> Self {
+        self.grow_toward(Direction::Left, insets.left)
+            .grow_toward(Direction::Up, insets.top)
+            .grow_toward(Direction::Right, insets.right)
+            .grow_toward(Direction::Down, insets.bottom)
+    }
+
+    /// Returns the rectangle shrunk by the given distance on each side.
+    ///
+    /// If the insets do not fit, the result is empty instead of inverted: the left and top insets
+    /// are applied first, and the right and bottom insets only take what is left.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use ixy::{Insets, Rect};
+    ///
+    /// let rect = Rect::from_ltwh(2, 2, 4, 4);
+    /// assert_eq!(rect.deflate_by(Insets::new(1, 0, 0, 2)), Rect::from_ltwh(3, 2, 3, 2));
+    /// assert_eq!(rect.deflate_by(Insets::new(5, 0, 0, 0)), Rect::from_ltwh(6, 2, 0, 4));
+    /// ```
+    #[must_use]
+    pub fn deflate_by(&self, insets: Insets) -> Self {
+        let shrink = |size: usize, start: usize, end: usize| {
+            let start = start.min(size);
+            (start, start + end.min(size - start))
+        };
+        let (left, dw) = shrink(self.width_usize(), insets.left, insets.right);
+        let (top, dh) = shrink(self.height_usize(), insets.top, insets.bottom);
+        Self {
+            x: self.x + T::from_usize(left),
+            y: self.y + T::from_usize(top),
+            w: self.w - T::from_usize(dw),
+            h: self.h - T::from_usize(dh),
+        }
+    }
+
+    /// Returns the parts of the rectangle that are not covered by `other`.
+    ///
+    /// Yields at most four non-overlapping, non-empty rectangles: the full-width band above
+    /// `other`, the full-width band below it, and then the parts to its left and right. If the
+    /// rectangles do not intersect, yields `self` (unless it is empty).
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use ixy::Rect;
+    ///
+    /// let rect = Rect::from_ltwh(0, 0, 4, 4);
+    /// let hole = Rect::from_ltwh(1, 1, 2, 2);
+    /// let parts: Vec<_> = rect.subtract(hole).collect();
+    /// assert_eq!(
+    ///     parts,
+    ///     &[
+    ///         Rect::from_ltwh(0, 0, 4, 1),
+    ///         Rect::from_ltwh(0, 3, 4, 1),
+    ///         Rect::from_ltwh(0, 1, 1, 2),
+    ///         Rect::from_ltwh(3, 1, 1, 2),
+    ///     ]
+    /// );
+    ///
+    /// // Removing the right half leaves only the left half.
+    /// let parts: Vec<_> = rect.subtract(Rect::from_ltwh(2, -5, 9, 9)).collect();
+    /// assert_eq!(parts, &[Rect::from_ltwh(0, 0, 2, 4)]);
+    /// ```
+    pub fn subtract(&self, other: Self) -> impl Iterator<Item = Self> + use<T> {
+        let parts = if self.intersects(other) {
+            let inner = self.intersect(other);
+            [
+                Self::from_ltrb_unchecked(self.left(), self.top(), self.right(), inner.top()),
+                Self::from_ltrb_unchecked(self.left(), inner.bottom(), self.right(), self.bottom()),
+                Self::from_ltrb_unchecked(self.left(), inner.top(), inner.left(), inner.bottom()),
+                Self::from_ltrb_unchecked(inner.right(), inner.top(), self.right(), inner.bottom()),
+            ]
+        } else {
+            [*self, Self::EMPTY, Self::EMPTY, Self::EMPTY]
+        };
+        parts.into_iter().filter(|part| !part.is_empty())
+    }
+
+    /// Splits the rectangle into a left and a right part at the column `x`.
+    ///
+    /// The left part covers `left..x` and the right part `x..right`. If `x` is outside the
+    /// rectangle, it is clamped, so one of the parts is empty.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use ixy::Rect;
+    ///
+    /// let rect = Rect::from_ltwh(2, 0, 6, 3);
+    /// assert_eq!(
+    ///     rect.split_at_x(5),
+    ///     (Rect::from_ltwh(2, 0, 3, 3), Rect::from_ltwh(5, 0, 3, 3))
+    /// );
+    /// assert_eq!(rect.split_at_x(100), (rect, Rect::from_ltwh(8, 0, 0, 3)));
+    /// ```
+    #[must_use]
+    pub fn split_at_x(&self, x: T) -> (Self, Self) {
+        let x = x.clamp(self.left(), self.right());
+        (
+            Self::from_ltrb_unchecked(self.left(), self.top(), x, self.bottom()),
+            Self::from_ltrb_unchecked(x, self.top(), self.right(), self.bottom()),
+        )
+    }
+
+    /// Splits the rectangle into a top and a bottom part at the row `y`.
+    ///
+    /// The top part covers `top..y` and the bottom part `y..bottom`. If `y` is outside the
+    /// rectangle, it is clamped, so one of the parts is empty.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use ixy::Rect;
+    ///
+    /// let rect = Rect::from_ltwh(0, 2, 3, 6);
+    /// assert_eq!(
+    ///     rect.split_at_y(3),
+    ///     (Rect::from_ltwh(0, 2, 3, 1), Rect::from_ltwh(0, 3, 3, 5))
+    /// );
+    /// assert_eq!(rect.split_at_y(-1), (Rect::from_ltwh(0, 2, 3, 0), rect));
+    /// ```
+    #[must_use]
+    pub fn split_at_y(&self, y: T) -> (Self, Self) {
+        let y = y.clamp(self.top(), self.bottom());
+        (
+            Self::from_ltrb_unchecked(self.left(), self.top(), self.right(), y),
+            Self::from_ltrb_unchecked(self.left(), y, self.right(), self.bottom()),
+        )
+    }
+
+    /// Splits the rectangle into a left part `width` cells wide, and the remaining right part.
+    ///
+    /// If `width` is larger than the rectangle, the right part is empty.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use ixy::Rect;
+    ///
+    /// let rect = Rect::from_ltwh(2, 0, 6, 3);
+    /// let (sidebar, content) = rect.split_at_width(2);
+    /// assert_eq!(sidebar, Rect::from_ltwh(2, 0, 2, 3));
+    /// assert_eq!(content, Rect::from_ltwh(4, 0, 4, 3));
+    /// ```
+    #[must_use]
+    pub fn split_at_width(&self, width: usize) -> (Self, Self) {
+        self.split_at_x(self.left() + T::from_usize(width.min(self.width_usize())))
+    }
+
+    /// Splits the rectangle into a top part `height` cells tall, and the remaining bottom part.
+    ///
+    /// If `height` is larger than the rectangle, the bottom part is empty.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use ixy::Rect;
+    ///
+    /// let rect = Rect::from_ltwh(0, 0, 10, 10);
+    /// let (header, body) = rect.split_at_height(1);
+    /// assert_eq!(header, Rect::from_ltwh(0, 0, 10, 1));
+    /// assert_eq!(body, Rect::from_ltwh(0, 1, 10, 9));
+    /// ```
+    #[must_use]
+    pub fn split_at_height(&self, height: usize) -> (Self, Self) {
+        self.split_at_y(self.top() + T::from_usize(height.min(self.height_usize())))
+    }
+
+    /// Interpolates each edge from `a` (at `num = 0`) to `b` (at `num = den`).
+    ///
+    /// Each edge is rounded to the nearest integer independently, with ties rounded away from
+    /// `a`, so for `num <= den` the edges never cross and `num == den` lands exactly on `b`.
+    ///
+    /// When extrapolating (`num > den`) towards a smaller rectangle, the edges may cross; the right
+    /// and bottom edges are then clamped to the left and top edges, producing an empty rectangle.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `den` is `0`, or if `num > den` and the result cannot be represented by `T`.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use ixy::Rect;
+    ///
+    /// let from = Rect::from_ltwh(0, 0, 10, 10);
+    /// let to = Rect::from_ltwh(20, 10, 4, 4);
+    /// assert_eq!(Rect::lerp(from, to, 0, 3), from);
+    /// assert_eq!(Rect::lerp(from, to, 1, 3), Rect::from_ltrb(7, 3, 15, 11).unwrap());
+    /// assert_eq!(Rect::lerp(from, to, 3, 3), to);
+    ///
+    /// let small = Rect::from_ltwh(0, 0, 2, 2);
+    /// assert_eq!(Rect::lerp(from, small, 2, 1), Rect::from_ltwh(0, 0, 0, 0));
+    /// ```
+    #[must_use]
+    pub fn lerp(a: Self, b: Self, num: usize, den: usize) -> Self {
+        let left = internal::lerp(a.left(), b.left(), num, den);
+        let top = internal::lerp(a.top(), b.top(), num, den);
+        let right = internal::lerp(a.right(), b.right(), num, den);
+        let bottom = internal::lerp(a.bottom(), b.bottom(), num, den);
+        Self::from_ltrb_unchecked(left, top, right.max(left), bottom.max(top))
+    }
+
+    /// Returns the four edges of the rectangle, each paired with the direction it faces.
+    ///
+    /// Edges are returned clockwise in the order of [`Direction::ALL`] (top, right, bottom,
+    /// left), and each edge runs clockwise between two corners, so the end of one edge is the
+    /// start of the next. Like [`Rect::bottom_right`], the corners are on the exclusive right and
+    /// bottom boundaries.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use ixy::{Direction, Pos, Rect, Segment};
+    ///
+    /// let rect = Rect::from_ltrb(1, 2, 4, 6).unwrap();
+    /// let [top, right, bottom, left] = rect.edges();
+    /// assert_eq!(top, (Direction::Up, Segment::new(Pos::new(1, 2), Pos::new(4, 2))));
+    /// assert_eq!(right, (Direction::Right, Segment::new(Pos::new(4, 2), Pos::new(4, 6))));
+    /// assert_eq!(bottom, (Direction::Down, Segment::new(Pos::new(4, 6), Pos::new(1, 6))));
+    /// assert_eq!(left, (Direction::Left, Segment::new(Pos::new(1, 6), Pos::new(1, 2))));
+    /// ```
+    #[must_use]
+    pub fn edges(&self) -> [(Direction, Segment<T>); 4] {
+        let [top_left, top_right, bottom_right, bottom_left] = self.corners();
+        [
+            (Direction::Up, Segment::new(top_left, top_right)),
+            (Direction::Right, Segment::new(top_right, bottom_right)),
+            (Direction::Down, Segment::new(bottom_right, bottom_left)),
+            (Direction::Left, Segment::new(bottom_left, top_left)),
+        ]
+    }
+
+    /// Returns the four corners of the rectangle, clockwise from the top-left.
+    ///
+    /// The order is top-left, top-right, bottom-right, bottom-left, which is also where each of
+    /// the [`Rect::edges`] starts. Iterate over them with `corners().into_iter()`.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use ixy::{Pos, Rect};
+    ///
+    /// let rect = Rect::from_ltrb(1, 2, 4, 6).unwrap();
+    /// assert_eq!(
+    ///     rect.corners(),
+    ///     [Pos::new(1, 2), Pos::new(4, 2), Pos::new(4, 6), Pos::new(1, 6)]
+    /// );
+    /// assert_eq!(rect.corners().into_iter().max(), Some(Pos::new(4, 6)));
+    /// ```
+    #[must_use]
+    pub fn corners(&self) -> [Pos<T>; 4] {
+        [
+            self.top_left(),
+            self.top_right(),
+            self.bottom_right(),
+            self.bottom_left(),
+        ]
+    }
+
+    /// Converts a position to coordinates relative to the top-left corner of the rectangle.
+    ///
+    /// Returns `None` if the position is outside the rectangle.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use ixy::{Pos, Rect};
+    ///
+    /// let viewport = Rect::from_ltwh(10, 20, 80, 24);
+    /// assert_eq!(viewport.to_local(Pos::new(15, 21)), Some(Pos::new(5, 1)));
+    /// assert_eq!(viewport.to_local(Pos::new(5, 21)), None);
+    /// ```
+    #[must_use]
+    pub fn to_local(&self, pos: Pos<T>) -> Option<Pos<T>> {
+        self.contains_pos(pos).then(|| pos - self.top_left())
+    }
+
+    /// Converts a position relative to the top-left corner of the rectangle to absolute
+    /// coordinates.
+    ///
+    /// This is the inverse of [`Rect::to_local`]. The position is not required to be inside the
+    /// rectangle.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use ixy::{Pos, Rect};
+    ///
+    /// let viewport = Rect::from_ltwh(10, 20, 80, 24);
+    /// assert_eq!(viewport.to_global(Pos::new(5, 1)), Pos::new(15, 21));
+    /// ```
+    #[must_use]
+    pub fn to_global(&self, local: Pos<T>) -> Pos<T> {
+        local + self.top_left()
+    }
+
+    /// Converts a rectangle to coordinates relative to the top-left corner of this rectangle.
+    ///
+    /// Returns `None` if `rect` is not entirely inside this rectangle.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use ixy::Rect;
+    ///
+    /// let panel = Rect::from_ltwh(10, 20, 80, 24);
+    /// let button = Rect::from_ltwh(12, 22, 10, 3);
+    /// assert_eq!(panel.to_local_rect(button), Some(Rect::from_ltwh(2, 2, 10, 3)));
+    /// assert_eq!(panel.to_local_rect(Rect::from_ltwh(85, 22, 10, 3)), None);
+    /// ```
+    #[must_use]
+    pub fn to_local_rect(&self, rect: Self) -> Option<Self> {
+        self.contains_rect(rect).then(|| rect - self.top_left())
+    }
+
+    /// Converts a rectangle relative to the top-left corner of this rectangle to absolute
+    /// coordinates.
+    ///
+    /// This is the inverse of [`Rect::to_local_rect`]. The rectangle is not required to be inside
+    /// this rectangle.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use ixy::Rect;
+    ///
+    /// let panel = Rect::from_ltwh(10, 20, 80, 24);
+    /// let button = Rect::from_ltwh(2, 2, 10, 3);
+    /// assert_eq!(panel.to_global_rect(button), Rect::from_ltwh(12, 22, 10, 3));
+    /// ```
+    #[must_use]
+    pub fn to_global_rect(&self, local: Self) -> Self {
+        local + self.top_left()
+    }
+
+    /// Returns the smallest rectangle that contains every position, or `None` if there are none.
+    ///
+    /// Also returns `None` if that rectangle cannot be represented by `T`, which happens when a
+    /// coordinate is `T::MAX` (the exclusive right or bottom edge would overflow), or when the
+    /// width or height would exceed `T::MAX`.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use ixy::{Pos, Rect};
+    ///
+    /// let path = [Pos::new(3, 1), Pos::new(-1, 4), Pos::new(2, 2)];
+    /// assert_eq!(Rect::bounding(path), Some(Rect::from_ltrb(-1, 1, 4, 5).unwrap()));
+    /// assert_eq!(Rect::<i32>::bounding([]), None);
+    /// assert_eq!(Rect::bounding([Pos::new(i32::MAX, 0)]), None);
+    /// ```
+    #[must_use]
+    pub fn bounding(positions: impl IntoIterator<Item = Pos<T>>) -> Option<Self> {
+        let mut iter = positions.into_iter();
+        let first = iter.next()?;
+        let (min, max) = iter.fold((first, first), |(min, max), pos| {
+            (
+                Pos::new(min.x.min(pos.x), min.y.min(pos.y)),
+                Pos::new(max.x.max(pos.x), max.y.max(pos.y)),
+            )
+        });
+        let right = max.x.checked_add(T::ONE)?;
+        let bottom = max.y.checked_add(T::ONE)?;
+        Some(Self {
+            x: min.x,
+            y: min.y,
+            w: T::ZERO.checked_add_u128(right.abs_diff_u128(min.x))?,
+            h: T::ZERO.checked_add_u128(bottom.abs_diff_u128(min.y))?,
+        })
+    }
+
+    /// Returns the position inside the rectangle that is closest to `pos`.
+    ///
+    /// Like [`Rect::contains_pos`], the right and bottom edges are exclusive, so the result is at
+    /// most `right - 1` and `bottom - 1`. On an axis where the rectangle is empty, the coordinate
+    /// is clamped to the left or top edge.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use ixy::{Pos, Rect};
+    ///
+    /// let screen = Rect::from_ltwh(0, 0, 80, 24);
+    /// assert_eq!(screen.clamp_pos(Pos::new(100, -3)), Pos::new(79, 0));
+    /// assert_eq!(screen.clamp_pos(Pos::new(5, 5)), Pos::new(5, 5));
+    /// ```
+    #[must_use]
+    pub fn clamp_pos(&self, pos: Pos<T>) -> Pos<T> {
+        let clamp = |value: T, start: T, len: T| {
+            let last = if len > T::ZERO {
+                start + len - T::ONE
+            } else {
+                start
+            };
+            value.clamp(start, last)
+        };
+        Pos::new(clamp(pos.x, self.x, self.w), clamp(pos.y, self.y, self.h))
+    }
+
+    /// Returns the rectangle moved, and if needed shrunk, so that it fits inside `container`.
+    ///
+    /// The rectangle is first shrunk to at most the size of `container`, keeping its top-left
+    /// corner, and then moved the shortest distance that places it inside `container`.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use ixy::Rect;
+    ///
+    /// let screen = Rect::from_ltwh(0, 0, 80, 24);
+    ///
+    /// // A tooltip hanging off the bottom-right corner is moved back on-screen.
+    /// let tooltip = Rect::from_ltwh(75, 22, 10, 3);
+    /// assert_eq!(tooltip.constrain_within(screen), Rect::from_ltwh(70, 21, 10, 3));
+    ///
+    /// // A window taller than the screen is also shrunk.
+    /// let window = Rect::from_ltwh(10, -5, 20, 40);
+    /// assert_eq!(window.constrain_within(screen), Rect::from_ltwh(10, 0, 20, 24));
+    /// ```
+    #[must_use]
+    pub fn constrain_within(&self, container: Self) -> Self {
+        let shrunk = Self {
+            x: self.x,
+            y: self.y,
+            w: self.w.min(container.w),
+            h: self.h.min(container.h),
+        };
+        shrunk.translate_within(Pos::new(T::ZERO, T::ZERO), container)
+    }
+
+    /// Returns the rectangle scaled by `num / den` about `pivot`.
+    ///
+    /// Each edge's distance from `pivot` is scaled and rounded to the nearest integer, with ties
+    /// rounded away from `pivot`, so the pivot stays fixed: a position at `pivot` is at the same
+    /// relative place before and after zooming.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `den` is `0`, or if the result cannot be represented by `T`.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use ixy::{Pos, Rect};
+    ///
+    /// let viewport = Rect::from_ltwh(0, 0, 80, 40);
+    /// let focus = Pos::new(20, 10);
+    ///
+    /// // Zoom in 2x around the focus point.
+    /// assert_eq!(viewport.scale_about(focus, 1, 2), Rect::from_ltwh(10, 5, 40, 20));
+    /// ```
+    #[must_use]
+    pub fn scale_about(&self, pivot: Pos<T>, num: usize, den: usize) -> Self {
+        Self::from_ltrb_unchecked(
+            internal::lerp(pivot.x, self.left(), num, den),
+            internal::lerp(pivot.y, self.top(), num, den),
+            internal::lerp(pivot.x, self.right(), num, den),
+            internal::lerp(pivot.y, self.bottom(), num, den),
+        )
+    }
+
+    /// Returns the rectangle scaled by `num / den` about its [`center`](Rect::center).
+    ///
+    /// See [`Rect::scale_about`] for rounding.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `den` is `0`, or if the result cannot be represented by `T`.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use ixy::Rect;
+    ///
+    /// let rect = Rect::from_ltwh(10, 10, 4, 6);
+    /// assert_eq!(rect.scale_from_center(2, 1), Rect::from_ltwh(8, 7, 8, 12));
+    /// assert_eq!(rect.scale_from_center(1, 2), Rect::from_ltwh(11, 11, 2, 4));
+    /// ```
+    #[must_use]
+    pub fn scale_from_center(&self, num: usize, den: usize) -> Self {
+        self.scale_about(self.center(), num, den)
+    }
+
+    /// Returns the largest rectangle with this rectangle's aspect ratio that fits inside
+    /// `container`, centered within it.
+    ///
+    /// The side that does not match `container` is rounded down, so the result never exceeds
+    /// `container`. Any leftover cell that cannot be split evenly goes to the right or bottom. An
+    /// empty rectangle results in an empty rectangle at the [`center`](Rect::center) of
+    /// `container`.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if the result cannot be represented in `T`.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use ixy::Rect;
+    ///
+    /// // Letterboxing a 16:9 framebuffer onto a 10:7 display.
+    /// let framebuffer = Rect::from_ltwh(0, 0, 320, 180);
+    /// let display = Rect::from_ltwh(0, 0, 1000, 700);
+    /// assert_eq!(framebuffer.fit_within(display), Rect::from_ltwh(0, 69, 1000, 562));
+    /// ```
+    #[must_use]
+    pub fn fit_within(&self, container: Self) -> Self {
+        self.scale_into(container, false)
+    }
+
+    /// Returns the smallest rectangle with this rectangle's aspect ratio that covers `container`,
+    /// centered on it.
+    ///
+    /// The side that does not match `container` is rounded up, so the result always covers
+    /// `container`. Any overhang that cannot be split evenly goes to the right or bottom. An empty
+    /// rectangle results in an empty rectangle at the [`center`](Rect::center) of `container`.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if the result cannot be represented in `T`.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use ixy::Rect;
+    ///
+    /// let framebuffer = Rect::from_ltwh(0, 0, 320, 180);
+    /// let display = Rect::from_ltwh(0, 0, 1000, 700);
+    /// assert_eq!(framebuffer.fill_within(display), Rect::from_ltwh(-122, 0, 1245, 700));
+    /// ```
+    #[must_use]
+    pub fn fill_within(&self, container: Self) -> Self {
+        self.scale_into(container, true)
+    }
+
+    /// Scales the rectangle to fit (or, if `cover`, to cover) `container`, centered on it.
+    fn scale_into(&self, container: Self, cover: bool) -> Self {
+        if self.is_empty() {
+            let center = container.center();
+            return Self::from_ltwh(center.x, center.y, 0, 0);
+        }
+        let (sw, sh) = (self.width_usize() as u128, self.height_usize() as u128);
+        let (cw, ch) = (
+            container.width_usize() as u128,
+            container.height_usize() as u128,
+        );
+        let scale = |n: u128, d: u128| {
+            let scaled = if cover { n.div_ceil(d) } else { n / d };
+            usize::try_from(scaled).unwrap_or(usize::MAX)
+        };
+        let (w, h) = if (sw * ch >= cw * sh) == cover {
+            (scale(sw * ch, sh), container.height_usize())
+        } else {
+            (container.width_usize(), scale(sh * cw, sw))
+        };
+        Self::from_ltwh(container.x, container.y, w, h).aligned_in(container, Align2::Center)
+    }
+
+    /// Returns a rectangle of the same size, placed inside `container` at the given anchor.
+    ///
+    /// When centering, any leftover cell that cannot be split evenly goes to the right or bottom.
+    /// A rectangle larger than `container` overhangs it on the side(s) away from the anchor, or
+    /// evenly on both sides when centered.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if the result cannot be represented in `T`.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use ixy::{Align2, Rect};
+    ///
+    /// let screen = Rect::from_ltwh(0, 0, 80, 24);
+    /// let toast = Rect::from_ltwh(0, 0, 30, 3);
+    ///
+    /// assert_eq!(toast.aligned_in(screen, Align2::BottomRight), Rect::from_ltwh(50, 21, 30, 3));
+    /// assert_eq!(toast.aligned_in(screen, Align2::Top), Rect::from_ltwh(25, 0, 30, 3));
+    /// assert_eq!(toast.aligned_in(screen, Align2::Left), Rect::from_ltwh(0, 10, 30, 3));
+    /// ```
+    #[must_use]
+    pub fn aligned_in(&self, container: Self, align: Align2) -> Self {
+        let (halves_x, halves_y) = align.halves();
+        let place = |start: T, outer: usize, inner: usize, halves: usize| {
+            if inner <= outer {
+                start + T::from_usize((outer - inner) * halves / 2)
+            } else {
+                start - T::from_usize((inner - outer) * halves / 2)
+            }
+        };
+        Self::from_ltwh(
+            place(
+                container.x,
+                container.width_usize(),
+                self.width_usize(),
+                halves_x,
+            ),
+            place(
+                container.y,
+                container.height_usize(),
+                self.height_usize(),
+                halves_y,
+            ),
+            self.width_usize(),
+            self.height_usize(),
+        )
+    }
+}
+
+/// Returns the base length and number of parts that are one longer when splitting `len` in `n`.
+fn split_evenly(len: usize, n: usize) -> (usize, usize) {
+    len.checked_div(n).map_or((0, 0), |base| (base, len % n))
+}
+
+impl<T: Display + Int> Display for Rect<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "Rect({}, {}, {}, {})", self.x, self.y, self.w, self.h)
+    }
+}
+
+impl<T: Int + FromStr> FromStr for Rect<T> {
+    type Err = RectError;
+
+    /// Parses a rectangle in one of two forms:
+    ///
+    /// - `l,t,w,h`: the top-left corner, and the width and height;
+    /// - `l,t..r,b`: the top-left and bottom-right corners.
+    ///
+    /// Whitespace around each number is ignored.
+    ///
+    /// ## Errors
+    ///
+    /// Returns [`RectError::InvalidFormat`] if the string is not in either form, and
+    /// [`RectError::InvalidDimensions`] if the numbers do not form a valid rectangle.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use ixy::{Rect, RectError};
+    ///
+    /// assert_eq!("10,20,30,40".parse(), Ok(Rect::from_ltwh(10, 20, 30, 40)));
+    /// assert_eq!("10,20..40,60".parse(), Ok(Rect::from_ltwh(10, 20, 30, 40)));
+    ///
+    /// assert_eq!("10,20,30".parse::<Rect>(), Err(RectError::InvalidFormat));
+    /// assert_eq!("10,20..0,0".parse::<Rect>(), Err(RectError::InvalidDimensions));
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Some((tl, br)) = s.split_once("..") {
+            let (left, top) = internal::parse_pair(tl).ok_or(RectError::InvalidFormat)?;
+            let (right, bottom) = internal::parse_pair(br).ok_or(RectError::InvalidFormat)?;
+            return Self::from_ltrb(left, top, right, bottom);
+        }
+        let (lt, wh) = s
+            .match_indices(',')
+            .nth(1)
+            .map(|(i, _)| (&s[..i], &s[i + 1..]))
+            .ok_or(RectError::InvalidFormat)?;
+        let (left, top) = internal::parse_pair(lt).ok_or(RectError::InvalidFormat)?;
+        let (width, height) = internal::parse_pair(wh).ok_or(RectError::InvalidFormat)?;
+        Self::from_ltwh_t(left, top, width, height)
+    }
+}
+
+#[cfg(feature = "rand")]
+impl<T: Int + rand::distr::uniform::SampleUniform> Rect<T> {
+    /// Returns a uniformly random position within the rectangle.
+    ///
+    /// Returns [`None`] if the rectangle is empty.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use ixy::{Pos, Rect};
+    ///
+    /// fn spawn_point(rng: &mut impl rand::Rng) -> Pos {
+    ///     let room = Rect::from_ltwh(10, 10, 8, 6);
+    ///     room.sample_pos(rng).expect("room is not empty")
+    /// }
+    /// ```
+    #[must_use]
+    pub fn sample_pos<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> Option<Pos<T>> {
+        if self.is_empty() {
+            return None;
+        }
+        let x = rng.random_range(self.left()..self.right());
+        let y = rng.random_range(self.top()..self.bottom());
+        Some(Pos::new(x, y))
+    }
+
+    /// Returns a random rectangle within this rectangle, with a size in `size_range`.
+    ///
+    /// The width and height are picked uniformly (and independently) from the range, limited to
+    /// the size of this rectangle, and then the position is picked uniformly from all positions
+    /// where the result fits.
+    ///
+    /// Returns [`None`] if the range is empty, or if its minimum size does not fit.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use ixy::{Rect, Size};
+    ///
+    /// fn random_room(rng: &mut impl rand::Rng) -> Rect {
+    ///     let map = Rect::from_ltwh(0, 0, 80, 40);
+    ///     map.sample_rect(rng, Size::new(4, 3)..=Size::new(12, 8))
+    ///         .expect("map fits the smallest room")
+    /// }
+    /// ```
+    #[must_use]
+    pub fn sample_rect<R: rand::Rng + ?Sized>(
+        &self,
+        rng: &mut R,
+        size_range: ops::RangeInclusive<Size>,
+    ) -> Option<Self> {
+        let (min, max) = size_range.into_inner();
+        let max_width = max.width.min(self.width_usize());
+        let max_height = max.height.min(self.height_usize());
+        if min.width > max_width || min.height > max_height {
+            return None;
+        }
+        let width = T::from_usize(rng.random_range(min.width..=max_width));
+        let height = T::from_usize(rng.random_range(min.height..=max_height));
+        let x = rng.random_range(self.left()..=self.right() - width);
+        let y = rng.random_range(self.top()..=self.bottom() - height);
+        Some(Self {
+            x,
+            y,
+            w: width,
+            h: height,
+        })
+    }
+}
+
+impl<T: Int> HasSize for Rect<T> {
+    fn size(&self) -> Size {
+        Size {
+            width: self.width_usize(),
+            height: self.height_usize(),
         }
     }
 }
@@ -546,6 +2179,7 @@ impl<T: Int> ops::Mul<T> for Rect<T> {
             w: self.w * rhs,
             h: self.h * rhs,
         }
+        .debug_validated()
     }
 }
 
@@ -555,6 +2189,7 @@ impl<T: Int> ops::MulAssign<T> for Rect<T> {
         self.y *= rhs;
         self.w *= rhs;
         self.h *= rhs;
+        *self = self.debug_validated();
     }
 }
 
@@ -568,6 +2203,7 @@ impl<T: Int> ops::Div<T> for Rect<T> {
             w: self.w / rhs,
             h: self.h / rhs,
         }
+        .debug_validated()
     }
 }
 
@@ -577,6 +2213,57 @@ impl<T: Int> ops::DivAssign<T> for Rect<T> {
         self.y /= rhs;
         self.w /= rhs;
         self.h /= rhs;
+        *self = self.debug_validated();
+    }
+}
+
+/// Intersection of two rectangles; see [`Rect::intersect`].
+///
+/// ## Examples
+///
+/// ```rust
+/// use ixy::Rect;
+///
+/// let a = Rect::from_ltrb(0, 0, 4, 4).unwrap();
+/// let b = Rect::from_ltrb(2, 2, 6, 6).unwrap();
+/// assert_eq!(a & b, Rect::from_ltrb(2, 2, 4, 4).unwrap());
+/// ```
+impl<T: Int> ops::BitAnd for Rect<T> {
+    type Output = Self;
+
+    fn bitand(self, rhs: Self) -> Self::Output {
+        self.intersect(rhs)
+    }
+}
+
+impl<T: Int> ops::BitAndAssign for Rect<T> {
+    fn bitand_assign(&mut self, rhs: Self) {
+        *self = self.intersect(rhs);
+    }
+}
+
+/// Bounding rectangle of two rectangles; see [`Rect::union`].
+///
+/// ## Examples
+///
+/// ```rust
+/// use ixy::Rect;
+///
+/// let a = Rect::from_ltrb(0, 0, 4, 4).unwrap();
+/// let b = Rect::from_ltrb(2, 2, 6, 6).unwrap();
+/// assert_eq!(a | b, Rect::from_ltrb(0, 0, 6, 6).unwrap());
+/// ```
+impl<T: Int> ops::BitOr for Rect<T> {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        self.union(rhs)
+    }
+}
+
+impl<T: Int> ops::BitOrAssign for Rect<T> {
+    fn bitor_assign(&mut self, rhs: Self) {
+        *self = self.union(rhs);
     }
 }
 
@@ -762,6 +2449,153 @@ mod tests {
         assert_eq!(rect.area(), 4);
     }
 
+    #[test]
+    fn from_pos_size() {
+        let rect = Rect::from_pos_size(Pos::new(-3i8, -4), Size::new(3, 4)).unwrap();
+        assert_eq!(rect, Rect::from_ltrb(-3, -4, 0, 0).unwrap());
+
+        let rect = Rect::from_pos_size(Pos::new(0u8, 0), Size::new(255, 255)).unwrap();
+        assert_eq!(rect.bottom_right(), Pos::new(255, 255));
+    }
+
+    #[test]
+    fn from_pos_size_tuple() {
+        let rect = Rect::from_pos_size(Pos::new(1, 2), (3, 4)).unwrap();
+        assert_eq!(rect, Rect::from_ltwh(1, 2, 3, 4));
+    }
+
+    #[test]
+    fn from_pos_size_negative_tuple() {
+        assert_eq!(
+            Rect::from_pos_size(Pos::new(1, 2), (-1, 2)),
+            Err(RectError::InvalidDimensions)
+        );
+        assert_eq!(
+            Rect::from_pos_size(Pos::new(1, 2), [2, -1]),
+            Err(RectError::InvalidDimensions)
+        );
+    }
+
+    #[test]
+    fn from_pos_size_overflow() {
+        assert_eq!(
+            Rect::from_pos_size(Pos::new(1u8, 0), Size::new(255, 1)),
+            Err(RectError::InvalidDimensions)
+        );
+        assert_eq!(
+            Rect::from_pos_size(Pos::new(0u8, 0), Size::new(1, 256)),
+            Err(RectError::InvalidDimensions)
+        );
+        assert_eq!(
+            Rect::from_pos_size(Pos::new(0i8, 100), Size::new(1, 28)),
+            Err(RectError::InvalidDimensions)
+        );
+    }
+
+    #[test]
+    fn from_str_ltwh() {
+        assert_eq!("1,2,3,4".parse(), Ok(Rect::from_ltwh(1, 2, 3, 4)));
+        assert_eq!(
+            " -1 , -2 , 3 , 4 ".parse(),
+            Ok(Rect::from_ltwh(-1, -2, 3, 4))
+        );
+        assert_eq!("0,0,0,0".parse::<Rect<u8>>(), Ok(Rect::EMPTY));
+        assert_eq!(
+            "0,0,-1,1".parse::<Rect>(),
+            Err(RectError::InvalidDimensions)
+        );
+        assert_eq!(
+            "250,0,10,1".parse::<Rect<u8>>(),
+            Err(RectError::InvalidDimensions)
+        );
+    }
+
+    #[test]
+    fn from_str_ltrb() {
+        assert_eq!("1,2..4,6".parse(), Ok(Rect::from_ltwh(1, 2, 3, 4)));
+        assert_eq!(" 1, 2 .. 4, 6 ".parse(), Ok(Rect::from_ltwh(1, 2, 3, 4)));
+        assert_eq!(
+            "4,2..1,6".parse::<Rect>(),
+            Err(RectError::InvalidDimensions)
+        );
+    }
+
+    #[test]
+    fn from_str_invalid_format() {
+        for s in [
+            "",
+            "1",
+            "1,2",
+            "1,2,3",
+            "1,2,3,4,5",
+            "1,2..3",
+            "a,b,c,d",
+            "1,2..3,4..5,6",
+        ] {
+            assert_eq!(s.parse::<Rect>(), Err(RectError::InvalidFormat), "{s:?}");
+        }
+        assert_eq!(
+            "300,0,1,1".parse::<Rect<u8>>(),
+            Err(RectError::InvalidFormat)
+        );
+    }
+
+    #[test]
+    fn from_ltwh_t() {
+        let rect = Rect::from_ltwh_t(1u8, 2, 3, 4).unwrap();
+        assert_eq!(rect, Rect::from_ltwh(1, 2, 3, 4));
+
+        let rect = Rect::from_ltwh_t(-1, -2, 0, 0).unwrap();
+        assert!(rect.is_empty());
+
+        assert_eq!(
+            Rect::from_ltwh_t(0, 0, 1, -1),
+            Err(RectError::InvalidDimensions)
+        );
+    }
+
+    #[test]
+    fn from_ltwh_t_far_corner() {
+        assert_eq!(
+            Rect::from_ltwh_t(250u8, 0, 5, 255).map(|r| r.bottom_right()),
+            Ok(Pos::new(255, 255))
+        );
+        assert_eq!(
+            Rect::<u8>::from_ltwh_t(250, 0, 10, 1),
+            Err(RectError::InvalidDimensions)
+        );
+        assert_eq!(
+            Rect::<i8>::from_ltwh_t(0, 100, 1, 28),
+            Err(RectError::InvalidDimensions)
+        );
+    }
+
+    #[test]
+    fn spans_round_trip() {
+        let rect = Rect::from_ltwh(-3, 4, 5, 6);
+        assert_eq!(Rect::from_spans(rect.x_span(), rect.y_span()), rect);
+    }
+
+    #[test]
+    fn spans_intersect_matches_rect_intersect() {
+        let a = Rect::from_ltwh(0, 0, 5, 5);
+        let b = Rect::from_ltwh(3, 2, 5, 5);
+        let spans = Rect::from_spans(
+            a.x_span().intersect(b.x_span()),
+            a.y_span().intersect(b.y_span()),
+        );
+        assert_eq!(spans, a.intersect(b));
+    }
+
+    #[test]
+    fn area_u64() {
+        let rect = Rect::from_ltrb(i64::MIN, 0, -1, 2).unwrap();
+        assert_eq!(rect.area_u64(), Some(i64::MAX as u64 * 2));
+
+        let rect = Rect::from_ltrb(0, 0, u64::MAX, 2).unwrap();
+        assert_eq!(rect.area_u64(), None);
+    }
+
     #[test]
     fn has_size() {
         let rect = Rect::from_ltrb(1, 2, 3, 4).unwrap();
@@ -985,4 +2819,613 @@ mod tests {
         assert_eq!(col_rect.right(), 2);
         assert_eq!(col_rect.bottom(), 6);
     }
+
+    #[test]
+    fn split_columns_even() {
+        let rect = Rect::from_ltwh(1, 2, 6, 3);
+        let columns: Vec<_> = rect.split_columns(3).collect();
+        assert_eq!(
+            columns,
+            &[
+                Rect::from_ltwh(1, 2, 2, 3),
+                Rect::from_ltwh(3, 2, 2, 3),
+                Rect::from_ltwh(5, 2, 2, 3),
+            ]
+        );
+    }
+
+    #[test]
+    fn split_columns_uneven() {
+        let rect = Rect::from_ltwh(0, 0, 7, 1);
+        let widths: Vec<_> = rect.split_columns(3).map(|r| r.width()).collect();
+        assert_eq!(widths, &[3, 2, 2]);
+    }
+
+    #[test]
+    fn split_columns_more_than_width() {
+        let rect = Rect::from_ltwh(0, 0, 2, 1);
+        let columns: Vec<_> = rect.split_columns(3).collect();
+        assert_eq!(
+            columns,
+            &[
+                Rect::from_ltwh(0, 0, 1, 1),
+                Rect::from_ltwh(1, 0, 1, 1),
+                Rect::from_ltwh(2, 0, 0, 1),
+            ]
+        );
+    }
+
+    #[test]
+    fn split_columns_zero() {
+        let rect = Rect::from_ltwh(0, 0, 2, 1);
+        assert_eq!(rect.split_columns(0).count(), 0);
+    }
+
+    #[test]
+    fn split_rows_uneven() {
+        let rect = Rect::from_ltwh(0, 1, 4, 8);
+        let rows: Vec<_> = rect.split_rows(3).collect();
+        assert_eq!(
+            rows,
+            &[
+                Rect::from_ltwh(0, 1, 4, 3),
+                Rect::from_ltwh(0, 4, 4, 3),
+                Rect::from_ltwh(0, 7, 4, 2),
+            ]
+        );
+    }
+
+    #[test]
+    fn snap_to_grid_aligned() {
+        let rect = Rect::from_ltwh(4, 8, 4, 4);
+        assert_eq!(rect.snap_to_grid(Size::new(4, 4)), rect);
+    }
+
+    #[test]
+    fn snap_to_grid_expands() {
+        let rect = Rect::from_ltwh(1, -1, 4, 2);
+        assert_eq!(
+            rect.snap_to_grid(Size::new(4, 4)),
+            Rect::from_ltwh(0, -4, 8, 8)
+        );
+    }
+
+    #[test]
+    fn hit_test_nine_zones() {
+        let rect = Rect::from_ltwh(10, 20, 9, 9);
+        let zones = [
+            [HitZone::TopLeft, HitZone::Top, HitZone::TopRight],
+            [HitZone::Left, HitZone::Interior, HitZone::Right],
+            [HitZone::BottomLeft, HitZone::Bottom, HitZone::BottomRight],
+        ];
+        for (row, zones) in (0..).zip(zones) {
+            for (col, zone) in (0..).zip(zones) {
+                let pos = Pos::new(10 + 3 * col + 1, 20 + 3 * row + 1);
+                assert_eq!(rect.hit_test(pos, 3), zone, "{pos}");
+            }
+        }
+    }
+
+    #[test]
+    fn hit_test_outside() {
+        let rect = Rect::from_ltwh(0, 0, 4, 4);
+        assert_eq!(rect.hit_test(Pos::new(-1, 0), 1), HitZone::Outside);
+        assert_eq!(rect.hit_test(Pos::new(4, 0), 1), HitZone::Outside);
+        assert_eq!(rect.hit_test(Pos::new(0, 4), 1), HitZone::Outside);
+    }
+
+    #[test]
+    fn hit_test_zero_thickness() {
+        let rect = Rect::from_ltwh(0, 0, 4, 4);
+        assert_eq!(rect.hit_test(Pos::new(0, 0), 0), HitZone::Interior);
+    }
+
+    #[test]
+    fn hit_test_small_rect_prefers_top_left() {
+        let rect = Rect::from_ltwh(0u8, 0, 2, 2);
+        assert_eq!(rect.hit_test(Pos::new(1, 1), 4), HitZone::TopLeft);
+    }
+
+    #[test]
+    fn grow_toward_each_direction() {
+        let rect = Rect::from_ltwh(0, 0, 2, 2);
+        assert_eq!(
+            rect.grow_toward(Direction::Up, 1),
+            Rect::from_ltwh(0, -1, 2, 3)
+        );
+        assert_eq!(
+            rect.grow_toward(Direction::Right, 1),
+            Rect::from_ltwh(0, 0, 3, 2)
+        );
+        assert_eq!(
+            rect.grow_toward(Direction::Down, 1),
+            Rect::from_ltwh(0, 0, 2, 3)
+        );
+        assert_eq!(
+            rect.grow_toward(Direction::Left, 1),
+            Rect::from_ltwh(-1, 0, 3, 2)
+        );
+    }
+
+    #[test]
+    fn shrink_from_reverses_grow_toward() {
+        let rect = Rect::from_ltwh(5u8, 5, 3, 4);
+        for direction in Direction::ALL {
+            assert_eq!(
+                rect.grow_toward(direction, 2).shrink_from(direction, 2),
+                rect
+            );
+        }
+    }
+
+    #[test]
+    fn grow_toward_saturates() {
+        let rect = Rect::from_ltwh(0u8, 1, 2, 2);
+        assert_eq!(rect.grow_toward(Direction::Left, 3), rect);
+        assert_eq!(
+            rect.grow_toward(Direction::Up, 3),
+            Rect::from_ltwh(0, 0, 2, 3)
+        );
+        assert_eq!(
+            rect.grow_toward(Direction::Right, 300),
+            Rect::from_ltwh(0, 1, 255, 2)
+        );
+        let rect = Rect::from_ltwh(-100i8, 0, 2, 2);
+        assert_eq!(
+            rect.grow_toward(Direction::Right, 300),
+            Rect::from_ltwh(-100, 0, 127, 2)
+        );
+    }
+
+    #[test]
+    fn inflate_saturates() {
+        let rect = Rect::from_ltwh(0u32, 0, 4, 4);
+        assert_eq!(rect.inflate(1), Rect::from_ltwh(0, 0, 5, 5));
+        let rect = Rect::from_ltwh(0i8, 0, 4, 4);
+        assert_eq!(rect.inflate(200), Rect::from_ltwh(-123, -123, 127, 127));
+        let rect = Rect::from_ltwh(250u8, 0, 2, 2);
+        assert_eq!(
+            rect.inflate_by(Insets::new(0, 0, 10, usize::MAX)),
+            Rect::from_ltwh(250, 0, 5, 255)
+        );
+    }
+
+    #[test]
+    fn shrink_from_saturates() {
+        let rect = Rect::from_ltwh(5u8, 5, 3, 4);
+        assert_eq!(
+            rect.shrink_from(Direction::Right, 10),
+            Rect::from_ltwh(5, 5, 0, 4)
+        );
+        assert_eq!(
+            rect.shrink_from(Direction::Left, 10),
+            Rect::from_ltwh(8, 5, 0, 4)
+        );
+        assert_eq!(
+            rect.shrink_from(Direction::Down, 10),
+            Rect::from_ltwh(5, 5, 3, 0)
+        );
+    }
+
+    #[test]
+    fn row_spans_empty() {
+        let rect = Rect::from_ltwh(3, 3, 0, 5);
+        assert_eq!(rect.row_spans().len(), 0);
+    }
+
+    #[test]
+    fn union_ignores_empty() {
+        let a = Rect::from_ltwh(10, 10, 2, 2);
+        let empty = Rect::from_ltwh(-5, -5, 0, 3);
+        assert_eq!(a.union(empty), a);
+        assert_eq!(empty.union(a), a);
+        assert_eq!(empty.union(Rect::EMPTY), empty);
+    }
+
+    #[test]
+    fn bit_ops_assign() {
+        let mut a = Rect::from_ltwh(0u8, 0, 4, 4);
+        a |= Rect::from_ltwh(6, 6, 2, 2);
+        assert_eq!(a, Rect::from_ltwh(0, 0, 8, 8));
+        a &= Rect::from_ltwh(2, 3, 10, 10);
+        assert_eq!(a, Rect::from_ltwh(2, 3, 6, 5));
+    }
+
+    #[test]
+    fn translate_within_clamps_each_axis() {
+        let bounds = Rect::from_ltwh(2u8, 2, 8, 8);
+        let rect = Rect::from_ltwh(3, 3, 2, 2);
+        assert_eq!(
+            rect.translate_within(Pos::new(1, 1), bounds),
+            Rect::from_ltwh(4, 4, 2, 2)
+        );
+        assert_eq!(
+            rect.translate_within(Pos::new(0, 20), bounds),
+            Rect::from_ltwh(3, 8, 2, 2)
+        );
+        assert_eq!(
+            rect.translate_within(Pos::new(20, 0), bounds),
+            Rect::from_ltwh(8, 3, 2, 2)
+        );
+    }
+
+    #[test]
+    fn translate_within_larger_than_bounds() {
+        let bounds = Rect::from_ltwh(0, 0, 4, 4);
+        let rect = Rect::from_ltwh(-3, 1, 6, 2);
+        assert_eq!(
+            rect.translate_within(Pos::new(-5, -5), bounds),
+            Rect::from_ltwh(0, 0, 6, 2)
+        );
+    }
+
+    #[test]
+    fn minkowski_sum_matches_pairwise_sums() {
+        let a = Rect::from_ltwh(1, -2, 3, 2);
+        let b = Rect::from_ltwh(-1, 4, 2, 3);
+        let sum = a.minkowski_sum(b);
+        let mut bounds = Rect::EMPTY;
+        for pa in RowMajor::iter_pos(a) {
+            for pb in RowMajor::iter_pos(b) {
+                bounds = bounds.union(Rect::from_tl_size(pa + pb, Size::new(1, 1)));
+            }
+        }
+        assert_eq!(sum, bounds);
+    }
+
+    #[test]
+    fn minkowski_sum_empty() {
+        let a = Rect::from_ltwh(1u8, 2, 3, 4);
+        let empty = Rect::from_ltwh(5, 5, 0, 2);
+        assert_eq!(a.minkowski_sum(empty), Rect::from_ltwh(6, 7, 0, 0));
+    }
+
+    #[test]
+    fn minkowski_difference_inverts_sum() {
+        let a = Rect::from_ltwh(2, 3, 5, 4);
+        let b = Rect::from_ltwh(-1, 1, 2, 3);
+        assert_eq!(a.minkowski_sum(b).minkowski_difference(b), a);
+    }
+
+    #[test]
+    fn minkowski_difference_too_large() {
+        let a = Rect::from_ltwh(2, 3, 2, 4);
+        let b = Rect::from_ltwh(0, 0, 3, 1);
+        assert!(a.minkowski_difference(b).is_empty());
+        assert_eq!(a.minkowski_difference(Rect::EMPTY), a);
+    }
+
+    #[test]
+    fn intersects_matches_intersect() {
+        let a = Rect::from_ltwh(2u8, 2, 4, 3);
+        for x in 0..9 {
+            for w in 0..4 {
+                let b = Rect::from_ltwh(x, 3, w, 1);
+                assert_eq!(a.intersects(b), !a.intersect(b).is_empty(), "{b}");
+                assert_eq!(a.intersects(b), b.intersects(a), "{b}");
+            }
+        }
+    }
+
+    #[test]
+    fn translate_matches_add_pos() {
+        let rect = Rect::from_ltwh(-3, 4, 5, 6);
+        assert_eq!(rect.translate(7, -8), rect + Pos::new(7, -8));
+        assert_eq!(rect.translate_x(7).translate_y(-8), rect.translate(7, -8));
+        assert_eq!(rect.checked_translate(7, -8), Some(rect.translate(7, -8)));
+    }
+
+    #[test]
+    fn deflate_reverses_inflate() {
+        let rect = Rect::from_ltwh(5u8, 5, 3, 2);
+        let insets = Insets::new(1, 2, 3, 4);
+        assert_eq!(rect.inflate_by(insets).deflate_by(insets), rect);
+        assert_eq!(rect.inflate(5).deflate(5), rect);
+    }
+
+    #[test]
+    fn deflate_clamps_to_empty() {
+        let rect = Rect::from_ltwh(0, 0, 4, 4);
+        assert_eq!(rect.deflate(2), Rect::from_ltwh(2, 2, 0, 0));
+        assert_eq!(
+            rect.deflate_by(Insets::new(1, 0, 9, 0)),
+            Rect::from_ltwh(1, 0, 0, 4)
+        );
+    }
+
+    #[test]
+    fn center_negative_odd_and_even() {
+        assert_eq!(Rect::from_ltwh(-5, -4, 5, 4).center(), Pos::new(-3, -2));
+        assert_eq!(Rect::from_ltwh(3u8, 3, 0, 1).center(), Pos::new(3, 3));
+    }
+
+    #[test]
+    fn subtract_covers_difference_exactly() {
+        let rect = Rect::from_ltwh(0, 0, 6, 5);
+        for other in [
+            Rect::from_ltwh(-1, -1, 3, 3),
+            Rect::from_ltwh(2, 1, 2, 10),
+            Rect::from_ltwh(0, 0, 6, 5),
+            Rect::from_ltwh(10, 10, 1, 1),
+            Rect::from_ltwh(-2, 2, 20, 1),
+        ] {
+            let parts: Vec<_> = rect.subtract(other).collect();
+            for pos in RowMajor::iter_pos(rect) {
+                let count = parts.iter().filter(|p| p.contains_pos(pos)).count();
+                let expected = usize::from(!other.contains_pos(pos));
+                assert_eq!(count, expected, "{other} at {pos}");
+            }
+            assert!(parts.iter().all(|p| rect.contains_rect(*p)));
+        }
+    }
+
+    #[test]
+    fn subtract_from_empty() {
+        let rect = Rect::from_ltwh(1, 1, 0, 4);
+        assert_eq!(rect.subtract(Rect::from_ltwh(0, 0, 1, 1)).count(), 0);
+    }
+
+    #[test]
+    fn split_at_parts_rejoin() {
+        let rect = Rect::from_ltwh(3u8, 4, 5, 6);
+        for i in 0..12 {
+            let (a, b) = rect.split_at_x(i);
+            assert_eq!(a.union(b), rect);
+            assert_eq!(a.area() + b.area(), rect.area());
+            let (a, b) = rect.split_at_height(usize::from(i));
+            assert_eq!(a.union(b), rect);
+            assert_eq!(a.area() + b.area(), rect.area());
+        }
+    }
+
+    #[test]
+    fn lerp_unsigned_shrinking() {
+        let a = Rect::from_ltwh(10u8, 10, 40, 40);
+        let b = Rect::from_ltwh(0, 20, 1, 1);
+        for num in 0..=7 {
+            let rect = Rect::lerp(a, b, num, 7);
+            assert!(rect.left() <= rect.right() && rect.top() <= rect.bottom());
+        }
+        assert_eq!(Rect::lerp(a, b, 7, 7), b);
+    }
+
+    #[test]
+    fn lerp_straddling_zero() {
+        let a = Rect::from_ltrb(-100i8, -100, -90, -90).unwrap();
+        let b = Rect::from_ltrb(90i8, 90, 100, 100).unwrap();
+        assert_eq!(
+            Rect::lerp(a, b, 1, 2),
+            Rect::from_ltrb(-5, -5, 5, 5).unwrap()
+        );
+        let rect = Rect::from_ltrb(20i8, 0, 28, 1).unwrap();
+        assert_eq!(
+            rect.scale_about(Pos::new(-100, 0), 1, 2),
+            Rect::from_ltrb(-40, 0, -36, 1).unwrap()
+        );
+    }
+
+    #[test]
+    fn lerp_extrapolating_shrink_is_empty() {
+        let a = Rect::from_ltwh(0, 0, 10, 10);
+        let b = Rect::from_ltwh(0, 0, 2, 2);
+        assert_eq!(Rect::lerp(a, b, 2, 1), Rect::from_ltwh(0, 0, 0, 0));
+        let b = Rect::from_ltwh(3, 1, 4, 8);
+        assert_eq!(Rect::lerp(a, b, 3, 1), Rect::from_ltrb(9, 3, 9, 7).unwrap());
+        assert_eq!(Rect::lerp(a, b, 5, 4), Rect::from_ltrb(4, 1, 6, 9).unwrap());
+    }
+
+    #[test]
+    fn edges_are_connected() {
+        let rect = Rect::from_ltwh(-3, 5, 2, 7);
+        let edges = rect.edges();
+        for (i, (direction, segment)) in edges.iter().enumerate() {
+            assert_eq!(*direction, Direction::ALL[i]);
+            assert_eq!(segment.end, edges[(i + 1) % 4].1.start);
+        }
+    }
+
+    #[test]
+    fn to_local_round_trip() {
+        let outer = Rect::from_ltwh(3u8, 4, 5, 6);
+        for pos in RowMajor::iter_pos(outer) {
+            let local = outer.to_local(pos).unwrap();
+            assert_eq!(outer.to_global(local), pos);
+        }
+        assert_eq!(outer.to_local(outer.bottom_right()), None);
+    }
+
+    #[test]
+    fn bounding_contains_all_positions() {
+        let rect = Rect::from_ltwh(2u8, 3, 4, 5);
+        assert_eq!(Rect::bounding(RowMajor::iter_pos(rect)), Some(rect));
+        assert_eq!(
+            Rect::bounding([Pos::new(7u8, 7)]),
+            Some(Rect::from_ltwh(7, 7, 1, 1))
+        );
+    }
+
+    #[test]
+    fn bounding_unrepresentable() {
+        assert_eq!(Rect::bounding([Pos::new(i32::MAX, 0)]), None);
+        assert_eq!(Rect::bounding([Pos::new(0u8, 255)]), None);
+        assert_eq!(
+            Rect::bounding([Pos::new(-128i8, 0), Pos::new(126, 0)]),
+            None
+        );
+        assert_eq!(
+            Rect::bounding([Pos::new(-128i8, 0), Pos::new(-2, 0)]),
+            Some(Rect::from_ltrb(-128, 0, -1, 1).unwrap())
+        );
+    }
+
+    #[test]
+    fn clamp_pos_is_contained() {
+        let rect = Rect::from_ltwh(2u8, 3, 4, 5);
+        for x in 0..10 {
+            for y in 0..10 {
+                let pos = Pos::new(x, y);
+                let clamped = rect.clamp_pos(pos);
+                assert!(rect.contains_pos(clamped), "{pos}");
+                if rect.contains_pos(pos) {
+                    assert_eq!(clamped, pos);
+                }
+            }
+        }
+        let empty = Rect::from_ltwh(2u8, 3, 0, 5);
+        assert_eq!(empty.clamp_pos(Pos::new(9, 9)), Pos::new(2, 7));
+    }
+
+    #[test]
+    fn constrain_within_is_contained() {
+        let container = Rect::from_ltwh(5u8, 5, 10, 6);
+        for rect in [
+            Rect::from_ltwh(0, 0, 3, 3),
+            Rect::from_ltwh(7, 7, 2, 2),
+            Rect::from_ltwh(14, 2, 20, 1),
+            Rect::from_ltwh(0, 20, 50, 50),
+        ] {
+            let constrained = rect.constrain_within(container);
+            assert!(container.contains_rect(constrained), "{rect}");
+            if container.contains_rect(rect) {
+                assert_eq!(constrained, rect);
+            }
+        }
+    }
+
+    #[test]
+    fn scale_about_round_trips() {
+        let rect = Rect::from_ltwh(-10, 4, 30, 12);
+        let pivot = Pos::new(2, 7);
+        assert_eq!(rect.scale_about(pivot, 1, 1), rect);
+        assert_eq!(rect.scale_about(pivot, 3, 1).scale_about(pivot, 1, 3), rect);
+        assert_eq!(
+            rect.scale_about(rect.top_left(), 2, 1).top_left(),
+            rect.top_left()
+        );
+        assert_eq!(rect.scale_from_center(5, 1).center(), rect.center());
+    }
+
+    #[test]
+    fn fit_and_fill_within() {
+        let container = Rect::from_ltwh(5, -3, 17, 11);
+        for (w, h) in [(1, 1), (4, 3), (3, 4), (17, 11), (100, 2), (2, 100)] {
+            let rect = Rect::from_ltwh(0, 0, w, h);
+            let fit = rect.fit_within(container);
+            assert!(container.contains_rect(fit), "{w}x{h}: {fit}");
+            assert!(fit.width() == 17 || fit.height() == 11, "{w}x{h}: {fit}");
+            let fill = rect.fill_within(container);
+            assert!(fill.contains_rect(container), "{w}x{h}: {fill}");
+            assert!(fill.width() == 17 || fill.height() == 11, "{w}x{h}: {fill}");
+        }
+        assert_eq!(
+            Rect::from_ltwh(0, 0, 8, 4).fit_within(container).size(),
+            Size::new(17, 8)
+        );
+        assert_eq!(
+            Rect::from_ltwh(0, 0, 8, 4).fill_within(container).size(),
+            Size::new(22, 11)
+        );
+        assert_eq!(
+            Rect::EMPTY.fit_within(container),
+            Rect::from_ltwh(13, 2, 0, 0)
+        );
+    }
+
+    #[test]
+    fn aligned_in_stays_inside() {
+        let container = Rect::from_ltwh(-4, 7, 15, 10);
+        let rect = Rect::from_ltwh(100, 100, 4, 3);
+        for align in Align2::ALL {
+            let aligned = rect.aligned_in(container, align);
+            assert_eq!(aligned.size(), rect.size());
+            assert!(container.contains_rect(aligned), "{align:?}: {aligned}");
+        }
+        assert_eq!(
+            rect.aligned_in(container, Align2::Center),
+            Rect::from_ltwh(1, 10, 4, 3)
+        );
+        assert_eq!(
+            Rect::from_ltwh(0, 0, 20, 10).aligned_in(container, Align2::TopRight),
+            Rect::from_ltwh(-9, 7, 20, 10)
+        );
+    }
+
+    #[test]
+    fn perimeter_of_degenerate_rects() {
+        assert_eq!(Rect::<i32>::EMPTY.perimeter(), 0);
+        assert_eq!(Rect::from_ltwh(3, 3, 0, 5).perimeter(), 10);
+        assert_eq!(Rect::from_ltwh(3, 3, 1, 1).perimeter(), 4);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "invalid rectangle: l=3, t=0, r=1, b=1")]
+    fn from_ltrb_unchecked_invalid_panics_in_debug() {
+        let _ = Rect::from_ltrb_unchecked(3, 0, 1, 1);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "invalid rectangle")]
+    fn mul_negative_panics_in_debug() {
+        let _ = Rect::from_ltwh(1, 1, 2, 2) * -1;
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "invalid rectangle")]
+    fn div_assign_negative_panics_in_debug() {
+        let mut rect = Rect::from_ltwh(1, 1, 2, 2);
+        rect /= -1;
+    }
+}
+
+#[cfg(all(test, feature = "rand"))]
+mod rand_tests {
+    use super::*;
+    use crate::internal::TestRng;
+
+    #[test]
+    fn sample_pos_within_rect() {
+        let mut rng = TestRng(0x2545_F491_4F6C_DD1D);
+        let rect = Rect::from_ltwh(-5, 3, 4, 2);
+        for _ in 0..100 {
+            let pos = rect.sample_pos(&mut rng).unwrap();
+            assert!(rect.contains_pos(pos), "{pos}");
+        }
+    }
+
+    #[test]
+    fn sample_pos_empty() {
+        let mut rng = TestRng(1);
+        assert_eq!(Rect::from_ltwh(0, 0, 0, 5).sample_pos(&mut rng), None);
+    }
+
+    #[test]
+    fn sample_rect_within_rect() {
+        let mut rng = TestRng(0x2545_F491_4F6C_DD1D);
+        let rect = Rect::from_ltwh(2u8, 2, 10, 6);
+        for _ in 0..100 {
+            let sample = rect
+                .sample_rect(&mut rng, Size::new(2, 2)..=Size::new(20, 4))
+                .unwrap();
+            assert!(rect.contains_rect(sample), "{sample}");
+            assert!((2..=10).contains(&sample.width_usize()));
+            assert!((2..=4).contains(&sample.height_usize()));
+        }
+    }
+
+    #[test]
+    fn sample_rect_does_not_fit() {
+        let mut rng = TestRng(1);
+        let rect = Rect::from_ltwh(0, 0, 3, 3);
+        assert_eq!(
+            rect.sample_rect(&mut rng, Size::new(4, 1)..=Size::new(5, 5)),
+            None
+        );
+        assert_eq!(
+            rect.sample_rect(&mut rng, Size::new(2, 2)..=Size::new(1, 1)),
+            None
+        );
+    }
 }
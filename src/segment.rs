@@ -0,0 +1,89 @@
+use crate::{Pos, int::Int};
+
+/// A straight line segment between two positions.
+///
+/// Unlike [`Span`](crate::Span), both endpoints are positions on the boundary between cells
+/// rather than cells themselves; see [`Rect::edges`](crate::Rect::edges).
+///
+/// ## Examples
+///
+/// ```rust
+/// use ixy::{Pos, Segment};
+///
+/// let segment = Segment::new(Pos::new(0, 0), Pos::new(4, 0));
+/// assert_eq!(segment.reversed(), Segment::new(Pos::new(4, 0), Pos::new(0, 0)));
+/// assert!(segment.is_horizontal());
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct Segment<T = i32> {
+    /// The position the segment starts at.
+    pub start: Pos<T>,
+
+    /// The position the segment ends at.
+    pub end: Pos<T>,
+}
+
+impl<T: Int> Segment<T> {
+    /// Creates a new segment from `start` to `end`.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use ixy::{Pos, Segment};
+    ///
+    /// let segment = Segment::new(Pos::new(1, 2), Pos::new(3, 4));
+    /// assert_eq!(segment.start, Pos::new(1, 2));
+    /// assert_eq!(segment.end, Pos::new(3, 4));
+    /// ```
+    #[must_use]
+    pub const fn new(start: Pos<T>, end: Pos<T>) -> Self {
+        Self { start, end }
+    }
+
+    /// Returns the same segment, from `end` to `start`.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use ixy::{Pos, Segment};
+    ///
+    /// let segment = Segment::new(Pos::new(1, 2), Pos::new(3, 4));
+    /// assert_eq!(segment.reversed().start, Pos::new(3, 4));
+    /// ```
+    #[must_use]
+    pub const fn reversed(&self) -> Self {
+        Self::new(self.end, self.start)
+    }
+
+    /// Returns `true` if both endpoints share the same `y` coordinate.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use ixy::{Pos, Segment};
+    ///
+    /// assert!(Segment::new(Pos::new(1, 2), Pos::new(5, 2)).is_horizontal());
+    /// assert!(!Segment::new(Pos::new(1, 2), Pos::new(1, 5)).is_horizontal());
+    /// ```
+    #[must_use]
+    pub fn is_horizontal(&self) -> bool {
+        self.start.y == self.end.y
+    }
+
+    /// Returns `true` if both endpoints share the same `x` coordinate.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use ixy::{Pos, Segment};
+    ///
+    /// assert!(Segment::new(Pos::new(1, 2), Pos::new(1, 5)).is_vertical());
+    /// assert!(!Segment::new(Pos::new(1, 2), Pos::new(5, 2)).is_vertical());
+    /// ```
+    #[must_use]
+    pub fn is_vertical(&self) -> bool {
+        self.start.x == self.end.x
+    }
+}
@@ -1,10 +1,11 @@
 use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign};
 
-use crate::{Pos, Rect};
+use crate::{Pos, Rect, int::Int};
 
 /// Represents a size in 2D space, with `width` and `height`.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct Size {
     /// Width.
     pub width: usize,
@@ -141,11 +142,107 @@ impl DivAssign<usize> for Size {
     }
 }
 
-use core::fmt;
+use core::{fmt, str::FromStr};
 
 impl fmt::Display for Size {
+    /// Formats the size as `WxH`, e.g. `800x600`.
+    ///
+    /// The output can be parsed back with [`str::parse`].
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}×{}", self.width, self.height)
+        write!(f, "{}x{}", self.width, self.height)
+    }
+}
+
+/// Error returned when parsing a [`Size`] from a string fails.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseSizeError;
+
+impl fmt::Display for ParseSizeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("invalid size, expected `WxH`")
+    }
+}
+
+impl core::error::Error for ParseSizeError {}
+
+impl FromStr for Size {
+    type Err = ParseSizeError;
+
+    /// Parses a size in the form `WxH`, e.g. `800x600`.
+    ///
+    /// The separator may also be `X` or `×`, and whitespace around either dimension is ignored.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use ixy::Size;
+    ///
+    /// assert_eq!("800x600".parse(), Ok(Size::new(800, 600)));
+    /// assert_eq!("80 × 24".parse(), Ok(Size::new(80, 24)));
+    /// assert!("800".parse::<Size>().is_err());
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (width, height) = s.split_once(['x', 'X', '×']).ok_or(ParseSizeError)?;
+        let width = width.trim().parse().map_err(|_| ParseSizeError)?;
+        let height = height.trim().parse().map_err(|_| ParseSizeError)?;
+        Ok(Self { width, height })
+    }
+}
+
+/// A type that can be converted into a [`Size`], if it describes a valid width and height.
+///
+/// Functions that accept `impl TryIntoSize` can be called with a [`Size`] or any other type that
+/// describes a width and height.
+pub trait TryIntoSize {
+    /// Converts `self` into a [`Size`].
+    ///
+    /// Returns [`None`] if either dimension is negative or cannot be represented by a [`usize`].
+    fn try_into_size(self) -> Option<Size>;
+}
+
+impl TryIntoSize for Size {
+    fn try_into_size(self) -> Option<Size> {
+        Some(self)
+    }
+}
+
+/// Converts a `(width, height)` pair of integers into a [`Size`].
+///
+/// Returns [`None`] if either dimension is negative or cannot be represented by a [`usize`].
+///
+/// ## Examples
+///
+/// ```rust
+/// use ixy::{Size, TryIntoSize};
+///
+/// assert_eq!((3usize, 4usize).try_into_size(), Some(Size::new(3, 4)));
+/// assert_eq!((3i64, 4i64).try_into_size(), Some(Size::new(3, 4)));
+/// assert_eq!((3i64, -4i64).try_into_size(), None);
+/// ```
+impl<T: Int> TryIntoSize for (T, T) {
+    fn try_into_size(self) -> Option<Size> {
+        Some(Size::new(
+            self.0.checked_to_usize()?,
+            self.1.checked_to_usize()?,
+        ))
+    }
+}
+
+/// Converts a `[width, height]` array of integers into a [`Size`].
+///
+/// Returns [`None`] if either dimension is negative or cannot be represented by a [`usize`].
+///
+/// ## Examples
+///
+/// ```rust
+/// use ixy::{Size, TryIntoSize};
+///
+/// assert_eq!([3u8, 4].try_into_size(), Some(Size::new(3, 4)));
+/// assert_eq!([-3i8, 4].try_into_size(), None);
+/// ```
+impl<T: Int> TryIntoSize for [T; 2] {
+    fn try_into_size(self) -> Option<Size> {
+        (self[0], self[1]).try_into_size()
     }
 }
 
@@ -168,6 +265,58 @@ pub trait HasSize {
     fn to_rect(&self) -> Rect<usize> {
         Rect::from_ltwh(0, 0, self.width(), self.height())
     }
+
+    /// Returns the local bounds of the object, i.e. a rectangle at `Pos::ORIGIN` with its size.
+    ///
+    /// This is an alias of [`HasSize::to_rect`]. For a [`Rect`], this ignores its position.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use ixy::{HasSize, Rect, Size};
+    ///
+    /// assert_eq!(Size::new(3, 2).local_bounds(), Rect::from_ltwh(0, 0, 3, 2));
+    /// assert_eq!(Rect::from_ltwh(5, 5, 3, 2).local_bounds(), Rect::from_ltwh(0, 0, 3, 2));
+    /// ```
+    fn local_bounds(&self) -> Rect<usize> {
+        self.to_rect()
+    }
+
+    /// Returns `true` if the position, relative to the top-left of the object, is within its
+    /// [local bounds](HasSize::local_bounds).
+    ///
+    /// For a [`Rect`], this differs from [`Rect::contains_pos`], which uses absolute positions.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use ixy::{HasSize, Pos, Size};
+    ///
+    /// let size = Size::new(3, 2);
+    /// assert!(size.contains_local_pos(Pos::new(2, 1)));
+    /// assert!(!size.contains_local_pos(Pos::new(3, 0)));
+    /// ```
+    fn contains_local_pos(&self, pos: Pos<usize>) -> bool {
+        pos.x < self.width() && pos.y < self.height()
+    }
+
+    /// Returns `true` if the rectangle, relative to the top-left of the object, is entirely within
+    /// its [local bounds](HasSize::local_bounds).
+    ///
+    /// For a [`Rect`], this differs from [`Rect::contains_rect`], which uses absolute positions.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use ixy::{HasSize, Rect, Size};
+    ///
+    /// let size = Size::new(3, 2);
+    /// assert!(size.contains_local_rect(Rect::from_ltwh(1, 0, 2, 2)));
+    /// assert!(!size.contains_local_rect(Rect::from_ltwh(1, 1, 2, 2)));
+    /// ```
+    fn contains_local_rect(&self, rect: Rect<usize>) -> bool {
+        self.to_rect().contains_rect(rect)
+    }
 }
 
 impl HasSize for Size {
@@ -200,6 +349,82 @@ mod tests {
         assert_eq!(rect.bottom(), 20);
     }
 
+    #[test]
+    fn local_bounds() {
+        let size = Size::new(10, 20);
+        assert_eq!(size.local_bounds(), size.to_rect());
+    }
+
+    #[test]
+    fn contains_local_pos() {
+        let size = Size::new(2, 3);
+        assert!(size.contains_local_pos(Pos::new(0, 0)));
+        assert!(size.contains_local_pos(Pos::new(1, 2)));
+        assert!(!size.contains_local_pos(Pos::new(2, 2)));
+        assert!(!size.contains_local_pos(Pos::new(1, 3)));
+        assert!(!Size::new(0, 0).contains_local_pos(Pos::new(0, 0)));
+    }
+
+    #[test]
+    fn contains_local_rect() {
+        let size = Size::new(4, 4);
+        assert!(size.contains_local_rect(Rect::from_ltwh(0, 0, 4, 4)));
+        assert!(size.contains_local_rect(Rect::from_ltwh(1, 1, 2, 2)));
+        assert!(!size.contains_local_rect(Rect::from_ltwh(3, 0, 2, 1)));
+    }
+
+    #[test]
+    fn rect_local_is_relative_to_top_left() {
+        let rect = Rect::from_ltwh(5, 5, 2, 2);
+        assert!(HasSize::contains_local_pos(&rect, Pos::new(0, 0)));
+        assert!(!rect.contains_pos(Pos::new(0, 0)));
+        assert_eq!(rect.local_bounds(), Rect::from_ltwh(0, 0, 2, 2));
+    }
+
+    #[test]
+    fn try_into_size_tuple() {
+        assert_eq!((1usize, 2usize).try_into_size(), Some(Size::new(1, 2)));
+        assert_eq!((1u8, 2u8).try_into_size(), Some(Size::new(1, 2)));
+        assert_eq!((0i32, 0i32).try_into_size(), Some(Size::new(0, 0)));
+    }
+
+    #[test]
+    fn try_into_size_array() {
+        assert_eq!([5usize, 6].try_into_size(), Some(Size::new(5, 6)));
+        assert_eq!([5i16, 6].try_into_size(), Some(Size::new(5, 6)));
+    }
+
+    #[test]
+    fn try_into_size_negative() {
+        assert_eq!((1i32, -1i32).try_into_size(), None);
+        assert_eq!([-1i32, 1].try_into_size(), None);
+    }
+
+    #[test]
+    fn display_round_trip() {
+        extern crate alloc;
+        use alloc::string::ToString;
+
+        let size = Size::new(800, 600);
+        assert_eq!(size.to_string(), "800x600");
+        assert_eq!(size.to_string().parse(), Ok(size));
+    }
+
+    #[test]
+    fn from_str_separators() {
+        assert_eq!("1x2".parse(), Ok(Size::new(1, 2)));
+        assert_eq!("1X2".parse(), Ok(Size::new(1, 2)));
+        assert_eq!("1×2".parse(), Ok(Size::new(1, 2)));
+        assert_eq!(" 1 x 2 ".parse(), Ok(Size::new(1, 2)));
+    }
+
+    #[test]
+    fn from_str_invalid() {
+        for s in ["", "x", "1x", "x2", "1x2x3", "-1x2", "1,2", "axb"] {
+            assert_eq!(s.parse::<Size>(), Err(ParseSizeError), "{s:?}");
+        }
+    }
+
     #[test]
     fn add_size_size() {
         let size1 = Size::new(10, 20);
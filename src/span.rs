@@ -0,0 +1,214 @@
+use crate::int::Int;
+
+/// A half-open, 1-dimensional range of integers, from `start` (inclusive) to `end` (exclusive).
+///
+/// A span where `end <= start` is empty. Each [`Rect`](crate::Rect) is the product of a
+/// horizontal and a vertical span; see [`Rect::x_span`](crate::Rect::x_span) and
+/// [`Rect::y_span`](crate::Rect::y_span).
+///
+/// ## Examples
+///
+/// ```rust
+/// use ixy::Span;
+///
+/// let a = Span::new(0, 10);
+/// let b = Span::new(5, 15);
+/// assert_eq!(a.intersect(b), Span::new(5, 10));
+/// assert_eq!(a.union(b), Span::new(0, 15));
+/// assert_eq!(a.len(), 10);
+/// ```
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct Span<T = i32> {
+    /// The first value in the span.
+    pub start: T,
+
+    /// The value one past the last value in the span.
+    pub end: T,
+}
+
+impl<T: Int> Span<T> {
+    /// Creates a new span from `start` (inclusive) to `end` (exclusive).
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use ixy::Span;
+    ///
+    /// assert_eq!(Span::new(3, 4), Span { start: 3, end: 4 });
+    /// ```
+    #[must_use]
+    pub const fn new(start: T, end: T) -> Self {
+        Self { start, end }
+    }
+
+    /// Returns `true` if the span contains no values.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use ixy::Span;
+    ///
+    /// assert!(Span::new(3, 3).is_empty());
+    /// assert!(Span::new(3, 1).is_empty());
+    /// assert!(!Span::new(1, 3).is_empty());
+    /// ```
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.end <= self.start
+    }
+
+    /// Returns the number of values in the span, or `0` if it is empty.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use ixy::Span;
+    ///
+    /// assert_eq!(Span::new(-2, 3).len(), 5);
+    /// assert_eq!(Span::new(3, -2).len(), 0);
+    /// ```
+    #[must_use]
+    pub fn len(&self) -> T {
+        if self.is_empty() {
+            T::ZERO
+        } else {
+            self.end - self.start
+        }
+    }
+
+    /// Returns `true` if the span contains `value`.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use ixy::Span;
+    ///
+    /// let span = Span::new(1, 3);
+    /// assert!(span.contains(1));
+    /// assert!(span.contains(2));
+    /// assert!(!span.contains(3));
+    /// ```
+    #[must_use]
+    pub fn contains(&self, value: T) -> bool {
+        value >= self.start && value < self.end
+    }
+
+    /// Returns `true` if the spans share at least one value.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use ixy::Span;
+    ///
+    /// assert!(Span::new(0, 5).overlaps(Span::new(4, 8)));
+    /// assert!(!Span::new(0, 5).overlaps(Span::new(5, 8)));
+    /// ```
+    #[must_use]
+    pub fn overlaps(&self, other: Self) -> bool {
+        !self.intersect(other).is_empty()
+    }
+
+    /// Returns the values contained in both spans.
+    ///
+    /// If the spans do not overlap, returns an empty span.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use ixy::Span;
+    ///
+    /// assert_eq!(Span::new(0, 5).intersect(Span::new(3, 8)), Span::new(3, 5));
+    /// assert!(Span::new(0, 5).intersect(Span::new(6, 8)).is_empty());
+    /// ```
+    #[must_use]
+    pub fn intersect(&self, other: Self) -> Self {
+        let start = self.start.max(other.start);
+        let end = self.end.min(other.end).max(start);
+        Self { start, end }
+    }
+
+    /// Returns the smallest span that contains both spans.
+    ///
+    /// Empty spans are ignored, so the union of a span with an empty span is the span itself.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use ixy::Span;
+    ///
+    /// assert_eq!(Span::new(0, 2).union(Span::new(5, 8)), Span::new(0, 8));
+    /// assert_eq!(Span::new(0, 2).union(Span::new(9, 9)), Span::new(0, 2));
+    /// ```
+    #[must_use]
+    pub fn union(&self, other: Self) -> Self {
+        if other.is_empty() {
+            return *self;
+        }
+        if self.is_empty() {
+            return other;
+        }
+        Self {
+            start: self.start.min(other.start),
+            end: self.end.max(other.end),
+        }
+    }
+}
+
+impl<T: Int> From<core::ops::Range<T>> for Span<T> {
+    fn from(range: core::ops::Range<T>) -> Self {
+        Self::new(range.start, range.end)
+    }
+}
+
+impl<T: Int> From<Span<T>> for core::ops::Range<T> {
+    fn from(span: Span<T>) -> Self {
+        span.start..span.end
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty_len() {
+        assert_eq!(Span::new(5u8, 5).len(), 0);
+        assert_eq!(Span::new(5u8, 1).len(), 0);
+    }
+
+    #[test]
+    fn contains_empty() {
+        assert!(!Span::new(2, 2).contains(2));
+    }
+
+    #[test]
+    fn intersect_disjoint_is_empty() {
+        let span = Span::new(0, 2).intersect(Span::new(4, 6));
+        assert!(span.is_empty());
+        assert_eq!(span.len(), 0);
+    }
+
+    #[test]
+    fn intersect_contained() {
+        assert_eq!(
+            Span::new(-10, 10).intersect(Span::new(-1, 1)),
+            Span::new(-1, 1)
+        );
+    }
+
+    #[test]
+    fn union_empty() {
+        let empty = Span::new(3, 3);
+        assert_eq!(empty.union(Span::new(5, 6)), Span::new(5, 6));
+        assert!(empty.union(empty).is_empty());
+    }
+
+    #[test]
+    fn range_round_trip() {
+        let span = Span::from(2..7);
+        assert_eq!(span, Span::new(2, 7));
+        assert_eq!(core::ops::Range::from(span), 2..7);
+    }
+}